@@ -5,6 +5,7 @@ register_plugin = function (importObject) {
     importObject.env.present_singleline_text_input = present_singleline_text_input;
     importObject.env.try_recv_text_input = try_recv_text_input;
     importObject.env._is_mobile = is_mobile;
+    importObject.env._timezone_offset = timezone_offset;
     importObject.env._reload_page = reload_page;
     importObject.env._open_url = open_url;
     importObject.env._loaded = loaded;
@@ -43,6 +44,9 @@ function is_mobile() {
     return check;
 }
 
+function timezone_offset() {
+    return new Date().getTimezoneOffset();
+}
 
 function reload_page() {
     location.reload();
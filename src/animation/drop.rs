@@ -2,33 +2,37 @@ use macroquad::prelude::*;
 
 use crate::{graphics, tileset, zone};
 
+use super::{easing::Easing, tween::Tween, Clock};
+
+const DROP_DURATION: f32 = 0.5;
+// Tile size factor at the start of the drop
+const DROP_START_SCALE: f32 = 66.0;
+
 pub struct TileDropAnimation {
     source: tileset::source::TileSource,
-    camera_dest: Vec2,
+    camera_center: Vec2,
     camera_dest_size: Vec2,
+    scale: Tween<f32>,
+    clock: Clock,
 }
 
 impl super::Animation for TileDropAnimation {
-    fn update(&mut self, _frame_i: i64) -> bool {
-        self.camera_dest_size /= 1.1;
-        self.camera_dest_size.length() < 0.05
+    fn update(&mut self, now: f64) -> bool {
+        self.clock.tick(now);
+        self.clock.elapsed() >= self.scale.duration()
     }
 
     fn draw_in_camera(&self, graphics: &graphics::Graphics) {
         // TODO : tick_i ?
         let source_rect = self.source.to_rect(0);
+        let dest_size = self.camera_dest_size * self.scale.value_at(self.clock.elapsed());
+        let dest = self.camera_center - dest_size / 2.;
         let params = DrawTextureParams {
-            dest_size: Some(self.camera_dest_size),
+            dest_size: Some(dest_size),
             source: Some(source_rect),
             ..Default::default()
         };
-        draw_texture_ex(
-            graphics.tileset_texture,
-            self.camera_dest.x,
-            self.camera_dest.y,
-            WHITE,
-            params,
-        );
+        graphics.draw_tileset_part(dest.x, dest.y, params);
     }
 }
 
@@ -40,7 +44,7 @@ impl TileDropAnimation {
         row_i: i32,
         col_i: i32,
     ) -> Result<Self, String> {
-        let source = match graphics.tiles_mapping.get(tile_id) {
+        let source = match graphics.tiles_mapping().get(tile_id) {
             Some(background_source_) => background_source_.clone(),
             None => return Err(format!("Tile id {} is unknown", tile_id)),
         };
@@ -54,11 +58,14 @@ impl TileDropAnimation {
 
         let dest_size_x = graphics.tile_width / map.concrete_width;
         let dest_size_y = graphics.tile_height / map.concrete_height;
+        let camera_dest_size = Vec2::new(dest_size_x, dest_size_y);
 
         Ok(Self {
             source,
-            camera_dest: Vec2::new(camera_dest_x, camera_dest_y),
-            camera_dest_size: Vec2::new(dest_size_x, dest_size_y) * 60.0 * 1.1,
+            camera_center: Vec2::new(camera_dest_x, camera_dest_y) + camera_dest_size / 2.,
+            camera_dest_size,
+            scale: Tween::new(DROP_START_SCALE, 1., DROP_DURATION, Easing::QuadOut),
+            clock: Clock::default(),
         })
     }
 }
@@ -0,0 +1,46 @@
+/// Easing curves, giving progression (0.0 to 1.0) from time progression (0.0 to 1.0)
+#[derive(Debug, Clone, Copy)]
+pub enum Easing {
+    Linear,
+    QuadIn,
+    QuadOut,
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2. - t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easings_go_from_zero_to_one() {
+        for easing in [Easing::Linear, Easing::QuadIn, Easing::QuadOut] {
+            assert_eq!(easing.apply(0.), 0., "{:?}", easing);
+            assert_eq!(easing.apply(1.), 1., "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn easings_are_clamped() {
+        for easing in [Easing::Linear, Easing::QuadIn, Easing::QuadOut] {
+            assert_eq!(easing.apply(-1.), 0., "{:?}", easing);
+            assert_eq!(easing.apply(2.), 1., "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn quad_easings_are_slow_at_their_start_or_end() {
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert!(Easing::QuadIn.apply(0.5) < 0.5);
+        assert!(Easing::QuadOut.apply(0.5) > 0.5);
+    }
+}
@@ -1,10 +1,42 @@
 pub mod drop;
+pub mod easing;
+pub mod particle;
 pub mod pop;
+pub mod text;
+pub mod tween;
 pub mod visible;
 
 use crate::graphics;
 
+/// Animations are time based (seconds) to not depend on frame rate
 pub trait Animation {
-    fn update(&mut self, frame_i: i64) -> bool;
+    /// Update animation at given time (see `macroquad::time::get_time`). Return true when finished
+    fn update(&mut self, now: f64) -> bool;
     fn draw_in_camera(&self, graphics: &graphics::Graphics);
 }
+
+/// Animation elapsed time, starting at its first update
+#[derive(Default)]
+pub struct Clock {
+    start: Option<f64>,
+    elapsed: f32,
+    delta: f32,
+}
+
+impl Clock {
+    pub fn tick(&mut self, now: f64) {
+        let start = *self.start.get_or_insert(now);
+        let elapsed = (now - start) as f32;
+        self.delta = elapsed - self.elapsed;
+        self.elapsed = elapsed;
+    }
+
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Elapsed time since previous tick
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+}
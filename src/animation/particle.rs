@@ -0,0 +1,70 @@
+use macroquad::prelude::*;
+
+struct Particle {
+    position: Vec2,
+    velocity: Vec2,
+    age: f32,
+    life: f32,
+}
+
+/// Lightweight particles (small fading squares). Units are the ones of the space where particles
+/// are drawn (camera or screen), per second
+pub struct ParticleEmitter {
+    particles: Vec<Particle>,
+    color: Color,
+    size: Vec2,
+    gravity: Vec2,
+}
+
+impl ParticleEmitter {
+    pub fn new(color: Color, size: Vec2, gravity: Vec2) -> Self {
+        Self {
+            particles: vec![],
+            color,
+            size,
+            gravity,
+        }
+    }
+
+    /// Emit particles from position in random directions
+    pub fn burst(&mut self, position: Vec2, count: usize, speed: f32, life: f32) {
+        for _ in 0..count {
+            let angle = rand::gen_range(0., std::f32::consts::TAU);
+            let speed = speed * rand::gen_range(0.5, 1.0);
+            self.particles.push(Particle {
+                position,
+                velocity: Vec2::new(angle.cos(), angle.sin()) * speed,
+                age: 0.,
+                life: life * rand::gen_range(0.7, 1.0),
+            });
+        }
+    }
+
+    pub fn update(&mut self, delta: f32) {
+        for particle in &mut self.particles {
+            particle.velocity += self.gravity * delta;
+            particle.position += particle.velocity * delta;
+            particle.age += delta;
+        }
+        self.particles
+            .retain(|particle| particle.age < particle.life);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    pub fn draw(&self) {
+        for particle in &self.particles {
+            let mut color = self.color;
+            color.a *= 1. - particle.age / particle.life;
+            draw_rectangle(
+                particle.position.x - self.size.x / 2.,
+                particle.position.y - self.size.y / 2.,
+                self.size.x,
+                self.size.y,
+                color,
+            );
+        }
+    }
+}
@@ -2,33 +2,48 @@ use macroquad::prelude::*;
 
 use crate::{graphics, tileset, zone};
 
+use super::{easing::Easing, particle::ParticleEmitter, tween::Tween, Clock};
+
+const POP_DURATION: f32 = 0.8;
+// Size (in camera units) reached by the tile at the end of the pop
+const POP_MAX_SIZE: f32 = 2.0;
+const SPARKS_COUNT: usize = 12;
+const SPARKS_LIFE: f32 = 0.6;
+const SPARKS_COLOR: Color = Color::new(1.0, 0.85, 0.3, 1.0);
+
 pub struct TilePopAnimation {
     source: tileset::source::TileSource,
-    camera_dest: Vec2,
+    camera_center: Vec2,
     camera_dest_size: Vec2,
+    scale: Tween<f32>,
+    alpha: Tween<f32>,
+    sparks: ParticleEmitter,
+    clock: Clock,
 }
 
 impl super::Animation for TilePopAnimation {
-    fn update(&mut self, _frame_i: i64) -> bool {
-        self.camera_dest_size *= 1.1;
-        self.camera_dest_size.length() > 2.0
+    fn update(&mut self, now: f64) -> bool {
+        self.clock.tick(now);
+        self.sparks.update(self.clock.delta());
+        self.clock.elapsed() >= self.scale.duration() && self.sparks.is_empty()
     }
 
     fn draw_in_camera(&self, graphics: &graphics::Graphics) {
-        // TODO : tick_i ?
-        let source_rect = self.source.to_rect(0);
-        let params = DrawTextureParams {
-            dest_size: Some(self.camera_dest_size),
-            source: Some(source_rect),
-            ..Default::default()
-        };
-        draw_texture_ex(
-            graphics.tileset_texture,
-            self.camera_dest.x,
-            self.camera_dest.y,
-            WHITE,
-            params,
-        );
+        let elapsed = self.clock.elapsed();
+        if elapsed < self.scale.duration() {
+            // TODO : tick_i ?
+            let source_rect = self.source.to_rect(0);
+            let dest_size = self.camera_dest_size * self.scale.value_at(elapsed);
+            let dest = self.camera_center - dest_size / 2.;
+            let params = DrawTextureParams {
+                dest_size: Some(dest_size),
+                source: Some(source_rect),
+                ..Default::default()
+            };
+            let color = Color::new(1., 1., 1., self.alpha.value_at(elapsed));
+            graphics.draw_tileset_part_ex(dest.x, dest.y, color, params);
+        }
+        self.sparks.draw();
     }
 }
 
@@ -40,7 +55,7 @@ impl TilePopAnimation {
         row_i: i32,
         col_i: i32,
     ) -> Result<Self, String> {
-        let source = match graphics.tiles_mapping.get(tile_id) {
+        let source = match graphics.tiles_mapping().get(tile_id) {
             Some(background_source_) => background_source_.clone(),
             None => return Err(format!("Tile id {} is unknown", tile_id)),
         };
@@ -54,11 +69,35 @@ impl TilePopAnimation {
 
         let dest_size_x = graphics.tile_width / map.concrete_width;
         let dest_size_y = graphics.tile_height / map.concrete_height;
+        let camera_dest_size = Vec2::new(dest_size_x, dest_size_y);
+        let camera_center = Vec2::new(camera_dest_x, camera_dest_y) + camera_dest_size / 2.;
+
+        // Harvest sparks, falling down (camera is Y inverted)
+        let mut sparks = ParticleEmitter::new(
+            SPARKS_COLOR,
+            camera_dest_size / 8.,
+            Vec2::new(0., -camera_dest_size.y * 6.),
+        );
+        sparks.burst(
+            camera_center,
+            SPARKS_COUNT,
+            camera_dest_size.length() * 2.,
+            SPARKS_LIFE,
+        );
 
         Ok(Self {
             source,
-            camera_dest: Vec2::new(camera_dest_x, camera_dest_y),
-            camera_dest_size: Vec2::new(dest_size_x, dest_size_y),
+            camera_center,
+            camera_dest_size,
+            scale: Tween::new(
+                1.,
+                (POP_MAX_SIZE / camera_dest_size.length()).max(1.),
+                POP_DURATION,
+                Easing::QuadIn,
+            ),
+            alpha: Tween::new(1., 0., POP_DURATION, Easing::QuadIn),
+            sparks,
+            clock: Clock::default(),
         })
     }
 }
@@ -0,0 +1,77 @@
+use macroquad::prelude::*;
+
+use crate::graphics;
+
+use super::{
+    easing::Easing,
+    tween::{Sequence, Tween},
+    Clock,
+};
+
+const FLOATING_TEXT_DURATION: f32 = 1.5;
+const FLOATING_TEXT_FADE_OUT_DURATION: f32 = 0.5;
+// Distance (screen pixels) covered by the text while rising
+const FLOATING_TEXT_RISE: f32 = 40.;
+pub const FLOATING_TEXT_FONT_SIZE: f32 = 22.;
+
+/// Text rising then fading out from a screen position (damage numbers, resource pop-ups)
+pub struct FloatingTextAnimation {
+    text: String,
+    dest: Vec2,
+    color: Color,
+    rise: Tween<f32>,
+    alpha: Sequence<f32>,
+    clock: Clock,
+}
+
+impl super::Animation for FloatingTextAnimation {
+    fn update(&mut self, now: f64) -> bool {
+        self.clock.tick(now);
+        self.clock.elapsed() >= self.alpha.duration()
+    }
+
+    fn draw_in_camera(&self, _graphics: &graphics::Graphics) {
+        let elapsed = self.clock.elapsed();
+        let text_width = measure_text(&self.text, None, FLOATING_TEXT_FONT_SIZE as u16, 1.0).width;
+        let x = self.dest.x - text_width / 2.;
+        let y = self.dest.y - self.rise.value_at(elapsed);
+        let alpha = self.alpha.value_at(elapsed);
+
+        // Shadow to keep text readable over any tile
+        draw_text(
+            &self.text,
+            x + 1.,
+            y + 1.,
+            FLOATING_TEXT_FONT_SIZE,
+            Color::new(0., 0., 0., alpha),
+        );
+        let mut color = self.color;
+        color.a *= alpha;
+        draw_text(&self.text, x, y, FLOATING_TEXT_FONT_SIZE, color);
+    }
+}
+
+impl FloatingTextAnimation {
+    /// Text centered on given screen position
+    pub fn new(text: String, dest: Vec2, color: Color) -> Self {
+        let hold_duration = FLOATING_TEXT_DURATION - FLOATING_TEXT_FADE_OUT_DURATION;
+        Self {
+            text,
+            dest,
+            color,
+            rise: Tween::new(
+                0.,
+                FLOATING_TEXT_RISE,
+                FLOATING_TEXT_DURATION,
+                Easing::QuadOut,
+            ),
+            alpha: Sequence::new(Tween::hold(1., hold_duration)).then(Tween::new(
+                1.,
+                0.,
+                FLOATING_TEXT_FADE_OUT_DURATION,
+                Easing::QuadIn,
+            )),
+            clock: Clock::default(),
+        }
+    }
+}
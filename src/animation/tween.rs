@@ -0,0 +1,134 @@
+use macroquad::prelude::*;
+
+use super::easing::Easing;
+
+pub trait Lerp: Copy {
+    fn lerp(self, to: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+/// Value going from a value to another during given duration (seconds)
+#[derive(Debug, Clone)]
+pub struct Tween<T: Lerp> {
+    from: T,
+    to: T,
+    duration: f32,
+    easing: Easing,
+}
+
+impl<T: Lerp> Tween<T> {
+    pub fn new(from: T, to: T, duration: f32, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+        }
+    }
+
+    /// Value which don't change during given duration
+    pub fn hold(value: T, duration: f32) -> Self {
+        Self::new(value, value, duration, Easing::Linear)
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn value_at(&self, elapsed: f32) -> T {
+        if self.duration <= 0. {
+            return self.to;
+        }
+
+        self.from
+            .lerp(self.to, self.easing.apply(elapsed / self.duration))
+    }
+}
+
+/// Tweens played one after the other
+#[derive(Debug, Clone)]
+pub struct Sequence<T: Lerp> {
+    tweens: Vec<Tween<T>>,
+}
+
+impl<T: Lerp> Sequence<T> {
+    pub fn new(first: Tween<T>) -> Self {
+        Self {
+            tweens: vec![first],
+        }
+    }
+
+    pub fn then(mut self, tween: Tween<T>) -> Self {
+        self.tweens.push(tween);
+        self
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.tweens.iter().map(|tween| tween.duration()).sum()
+    }
+
+    pub fn value_at(&self, elapsed: f32) -> T {
+        let mut tween_start = 0.;
+        for tween in &self.tweens {
+            if elapsed < tween_start + tween.duration() {
+                return tween.value_at(elapsed - tween_start);
+            }
+            tween_start += tween.duration();
+        }
+
+        // Sequence is finished, stay on last value
+        let last = self
+            .tweens
+            .last()
+            .expect("Sequence have at least one tween");
+        last.value_at(last.duration())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tween_interpolates_during_its_duration() {
+        let tween = Tween::new(10., 20., 2., Easing::Linear);
+
+        assert_eq!(tween.value_at(0.), 10.);
+        assert_eq!(tween.value_at(1.), 15.);
+        assert_eq!(tween.value_at(2.), 20.);
+        // Stay on its final value once finished
+        assert_eq!(tween.value_at(5.), 20.);
+    }
+
+    #[test]
+    fn tween_without_duration_is_at_its_final_value() {
+        let tween = Tween::new(Vec2::ZERO, Vec2::ONE, 0., Easing::QuadIn);
+
+        assert_eq!(tween.value_at(0.), Vec2::ONE);
+    }
+
+    #[test]
+    fn sequence_plays_tweens_one_after_the_other() {
+        let sequence = Sequence::new(Tween::new(0., 1., 1., Easing::Linear))
+            .then(Tween::hold(1., 2.))
+            .then(Tween::new(1., 0., 1., Easing::Linear));
+
+        assert_eq!(sequence.duration(), 4.);
+        assert_eq!(sequence.value_at(0.5), 0.5);
+        assert_eq!(sequence.value_at(2.), 1.);
+        assert_eq!(sequence.value_at(3.5), 0.5);
+        // Stay on last tween final value once finished
+        assert_eq!(sequence.value_at(10.), 0.);
+    }
+}
@@ -2,15 +2,26 @@ use macroquad::prelude::*;
 
 use crate::graphics;
 
+use super::{
+    easing::Easing,
+    tween::{Sequence, Tween},
+    Clock,
+};
+
+const FADE_IN_DURATION: f32 = 0.2;
+const FADE_OUT_DURATION: f32 = 0.5;
+
 pub struct VisibleAnimation {
     source: Rect,
     dest: Vec2,
-    displayed_until_frame_i: i64,
+    alpha: Sequence<f32>,
+    clock: Clock,
 }
 
 impl super::Animation for VisibleAnimation {
-    fn update(&mut self, frame_i: i64) -> bool {
-        frame_i > self.displayed_until_frame_i
+    fn update(&mut self, now: f64) -> bool {
+        self.clock.tick(now);
+        self.clock.elapsed() >= self.alpha.duration()
     }
 
     fn draw_in_camera(&self, graphics: &graphics::Graphics) {
@@ -18,22 +29,22 @@ impl super::Animation for VisibleAnimation {
             source: Some(self.source),
             ..Default::default()
         };
-        draw_texture_ex(
-            graphics.tileset_texture,
-            self.dest.x,
-            self.dest.y,
-            WHITE,
-            params,
-        );
+        let color = Color::new(1., 1., 1., self.alpha.value_at(self.clock.elapsed()));
+        graphics.draw_tileset_part_ex(self.dest.x, self.dest.y, color, params);
     }
 }
 
 impl VisibleAnimation {
-    pub fn new(source: Rect, dest: Vec2, displayed_until_frame_i: i64) -> Self {
+    /// Display source at dest during given duration (seconds), with fade in and fade out
+    pub fn new(source: Rect, dest: Vec2, duration: f32) -> Self {
+        let hold_duration = (duration - FADE_IN_DURATION - FADE_OUT_DURATION).max(0.);
         Self {
             source,
             dest,
-            displayed_until_frame_i,
+            alpha: Sequence::new(Tween::new(0., 1., FADE_IN_DURATION, Easing::QuadOut))
+                .then(Tween::hold(1., hold_duration))
+                .then(Tween::new(1., 0., FADE_OUT_DURATION, Easing::Linear)),
+            clock: Clock::default(),
         }
     }
 }
@@ -0,0 +1,118 @@
+// Render a zone into a PNG image without opening any window (usable without GPU).
+// Example : render_zone zone.txt tiles.json zone.png --zone-type-id JUNGLE
+use std::{fs, path::PathBuf};
+
+use image::{io::Reader as ImageReader, GenericImageView};
+use serde::de::DeserializeOwned;
+use structopt::StructOpt;
+
+use rollgui2::{config, entity, hardcoded, tileset, zone};
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "render_zone")]
+struct Opt {
+    /// Zone source text map (containing ::GEO section)
+    #[structopt(parse(from_os_str))]
+    map: PathBuf,
+
+    /// Tiles definitions json file (as given by server)
+    #[structopt(parse(from_os_str))]
+    tiles: PathBuf,
+
+    /// Output png file
+    #[structopt(parse(from_os_str))]
+    output: PathBuf,
+
+    /// Tile set image
+    #[structopt(long, parse(from_os_str), default_value = "static/graphics.png")]
+    tile_set: PathBuf,
+
+    /// Tile set manifest (tiles definitions of the tile set image)
+    #[structopt(long, parse(from_os_str), default_value = "static/graphics.json")]
+    tile_set_manifest: PathBuf,
+
+    /// Zone type id used to determine background tile (JUNGLE, SEA, PLAIN, ...)
+    #[structopt(long, default_value = "PLAIN")]
+    zone_type_id: String,
+
+    /// Builds json file (list of builds as given by server)
+    #[structopt(long, parse(from_os_str))]
+    builds: Option<PathBuf>,
+
+    /// Stuffs json file (list of stuffs as given by server)
+    #[structopt(long, parse(from_os_str))]
+    stuffs: Option<PathBuf>,
+
+    /// Resources json file (list of resources as given by server)
+    #[structopt(long, parse(from_os_str))]
+    resources: Option<PathBuf>,
+}
+
+fn read_json<T: DeserializeOwned>(path: &PathBuf) -> Result<T, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read '{}' : {}", path.display(), error))?;
+    serde_json::from_str(&content)
+        .map_err(|error| format!("Unable to decode '{}' : {}", path.display(), error))
+}
+
+fn read_optional_json<T: DeserializeOwned>(path: &Option<PathBuf>) -> Result<Vec<T>, String> {
+    match path {
+        Some(path) => read_json(path),
+        None => Ok(vec![]),
+    }
+}
+
+fn run(opt: Opt) -> Result<(), String> {
+    let tile_set_image = ImageReader::open(&opt.tile_set)
+        .map_err(|error| format!("Unable to open '{}' : {}", opt.tile_set.display(), error))?
+        .decode()
+        .map_err(|error| format!("Unable to decode '{}' : {}", opt.tile_set.display(), error))?;
+    let manifest_bytes = fs::read(&opt.tile_set_manifest).map_err(|error| {
+        format!(
+            "Unable to read '{}' : {}",
+            opt.tile_set_manifest.display(),
+            error
+        )
+    })?;
+    let manifest = tileset::manifest::TilesetManifest::from_bytes(&manifest_bytes)?;
+    let (image_width, image_height) = tile_set_image.dimensions();
+    let tiles_mapping =
+        tileset::loader::from_manifest(&manifest, image_width as f32, image_height as f32)
+            .map_err(|error| {
+                format!("Invalid '{}' : {}", opt.tile_set_manifest.display(), error)
+            })?;
+    let (tile_width, tile_height) = (config::TILE_SIZE, config::TILE_SIZE);
+
+    let tiles: Vec<entity::tile::Tile> = read_json(&opt.tiles)?;
+    let source = fs::read_to_string(&opt.map)
+        .map_err(|error| format!("Unable to read '{}' : {}", opt.map.display(), error))?;
+    let default_tile_id = hardcoded::get_default_tile_id_for_zone_type_id(&opt.zone_type_id)
+        .ok_or(format!("Unknown zone type id {}", opt.zone_type_id))?;
+    let map = zone::load::from_txt_map(&source, tiles, tile_width, tile_height, &default_tile_id)?;
+
+    let builds: Vec<entity::build::Build> = read_optional_json(&opt.builds)?;
+    let stuffs: Vec<entity::stuff::Stuff> = read_optional_json(&opt.stuffs)?;
+    let resources: Vec<entity::resource::Resource> = read_optional_json(&opt.resources)?;
+
+    let renderer = zone::render::ZoneRenderer::new(
+        &tile_set_image,
+        &tiles_mapping,
+        tile_width,
+        tile_height,
+        manifest.atlas_scale(),
+    );
+    renderer
+        .render(&map, &builds, &stuffs, &resources)
+        .save(&opt.output)
+        .map_err(|error| format!("Unable to write '{}' : {}", opt.output.display(), error))?;
+
+    println!("Zone rendered into {}", opt.output.display());
+    Ok(())
+}
+
+fn main() {
+    if let Err(error) = run(Opt::from_args()) {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
@@ -1,7 +1,7 @@
 use macroquad::prelude::*;
 use quad_net::http_request::{Method, Request, RequestBuilder};
 
-use crate::{entity, types::AvatarUuid, SERVER_ADDRESS};
+use crate::{entity, i18n, types::AvatarUuid, SERVER_ADDRESS};
 
 #[derive(Clone)]
 pub struct Client {
@@ -37,7 +37,7 @@ impl Client {
                         .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
                     match response_object["message"].as_str() {
                         Some(message) => Ok(message.to_string()),
-                        None => Ok("Erreur inconnue".to_string()),
+                        None => Ok(i18n::tr("unknown_error").to_string()),
                     }
                 }
                 ureq::Error::Transport(transport) => Ok(format!("Transport error : {}", transport)),
@@ -60,7 +60,7 @@ impl Client {
                     // This is an error json
                     match response_object["message"].as_str() {
                         Some(message) => Err(message.to_string()),
-                        None => Err("Erreur inconnue".to_string()),
+                        None => Err(i18n::tr("unknown_error").to_string()),
                     }
                 } else {
                     entity::description::Description::from_string(&response_body)
@@ -79,7 +79,7 @@ impl Client {
                 entity::description::Description::from_string(&description_string)
             }
             Err(http_error) => Err(Self::error_message_from_http_error(http_error)
-                .unwrap_or("Erreur inconnue".to_string())),
+                .unwrap_or(i18n::tr("unknown_error").to_string())),
         }
     }
 
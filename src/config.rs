@@ -3,3 +3,5 @@
 
 pub const SPRITES_COUNT: i16 = 6;
 pub const SPRITES9_COUNT: i16 = 9;
+// Logical tile size : zone placement is computed with it, whatever the tileset resolution
+pub const TILE_SIZE: f32 = 32.;
@@ -29,11 +29,10 @@ impl UiDescription {
 
             // Illustration case
             if let Some(illustration_name) = &self.description.illustration_name {
-                if let Some(illustration_data) = self.graphics.illustrations.get(illustration_name)
-                {
+                if let Some(illustration_data) = self.graphics.illustration(illustration_name) {
                     let illustration_texture: egui::TextureHandle = ui.ctx().load_texture(
                         illustration_name,
-                        illustration_data.clone(),
+                        illustration_data,
                         TextureFilter::Linear,
                     );
                     self.illustration_texture = Some(illustration_texture);
@@ -45,38 +44,6 @@ impl UiDescription {
                 }
             }
 
-            // Prepare textures for tiles images
-            for part in &self.description.items {
-                let classes = if part.use_classes2_for_button {
-                    &part.classes2
-                } else {
-                    &part.classes
-                };
-                let tile_id = &self.graphics.find_tile_id_from_classes(classes);
-                if tile_id != "UNKNOWN" {
-                    if let Some(image_data) = self.graphics.tiles_data.get(tile_id) {
-                        let texture: egui::TextureHandle = ui.ctx().load_texture(
-                            tile_id,
-                            image_data.clone(),
-                            TextureFilter::Linear,
-                        );
-                        self.tiles_textures.insert(tile_id.to_string(), texture);
-
-                        // Prepare texture with action points if needed
-                        if let Some(cost) = part.cost {
-                            if let Some(tile_with_ap) = self.graphics.tile_with_ap(tile_id, cost) {
-                                let name = format!("{}__{}AP", tile_id, cost);
-                                let texture: egui::TextureHandle = ui.ctx().load_texture(
-                                    &name,
-                                    tile_with_ap.clone(),
-                                    TextureFilter::Linear,
-                                );
-                                self.tiles_textures.insert(name, texture);
-                            }
-                        }
-                    }
-                }
-            }
             self.is_first_frame = false;
         }
         None
@@ -102,19 +69,16 @@ impl UiDescription {
             ui.add_sized(BIG_BUTTON_SIZE, egui::Button::new(&label))
                 .clicked()
         } else {
-            let tile_name = if let Some(cost) = part.cost {
-                if cost > 0. {
-                    format!("{}__{}AP", &tile_id, cost)
-                } else {
-                    tile_id.clone()
-                }
+            // Tiles without known image are drawn as text buttons
+            let texture = if tile_id != "UNKNOWN" {
+                let cost = part.cost.filter(|cost| *cost > 0.);
+                self.graphics.tile_texture(ui.ctx(), &tile_id, cost)
             } else {
-                tile_id.clone()
+                None
             };
-
-            if let Some(texture) = self.tiles_textures.get(&tile_name) {
+            if let Some(texture) = texture {
                 ui.add(egui::ImageButton::new(
-                    texture,
+                    &texture,
                     egui::Vec2::new(IMG_BUTTON_SIZE[0], IMG_BUTTON_SIZE[1]),
                 ))
                 .on_hover_text(&label)
@@ -5,6 +5,7 @@ use macroquad::prelude::*;
 use crate::engine::zone::util::tight_display;
 use crate::entity;
 use crate::graphics;
+use crate::i18n;
 use crate::ui as base_ui;
 use crate::ui::utils::egui_scale;
 use crate::ui::utils::is_mobile;
@@ -44,7 +45,6 @@ pub struct UiDescription {
     pub loading: bool,
     pub draw_big_button: bool,
     pub text_input_request: Option<base_ui::text_input::TextInputRequest>,
-    pub tiles_textures: HashMap<String, egui::TextureHandle>,
     pub illustration_texture: Option<egui::TextureHandle>,
     pub illustration_load_requested: bool,
 }
@@ -79,7 +79,6 @@ impl UiDescription {
             loading: false,
             draw_big_button: false,
             text_input_request: None,
-            tiles_textures: HashMap::new(),
             illustration_texture: None,
             illustration_load_requested: false,
         }
@@ -109,11 +108,11 @@ impl UiDescription {
         self.manage_pending(state);
 
         ui.horizontal(|ui| {
-            if ui.button("Fermer").clicked() {
+            if ui.button(i18n::tr("description.close")).clicked() {
                 ui_message = Some(UiDescriptionEvent::CloseDescription);
             };
             if let Some(previous_) = &self.previous {
-                if ui.button("Précédent").clicked() {
+                if ui.button(i18n::tr("description.previous")).clicked() {
                     ui_message = Some(UiDescriptionEvent::SetDescriptionUi(previous_.clone()));
                 }
             };
@@ -122,7 +121,7 @@ impl UiDescription {
         ui.separator();
 
         if self.loading {
-            ui.label("Loading...");
+            ui.label(i18n::tr("loading"));
             return ui_message;
         }
 
@@ -346,7 +345,10 @@ impl UiDescription {
             }
             ui.end_row();
         }
-        let submit_label = part.submit_label.clone().unwrap_or("Valider".to_string());
+        let submit_label = part
+            .submit_label
+            .clone()
+            .unwrap_or(i18n::tr("description.submit").to_string());
         if ui.button(submit_label).clicked() {
             if let Some(url) = &part.form_action {
                 if part.form_values_in_query {
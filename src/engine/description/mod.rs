@@ -114,10 +114,4 @@ impl super::Engine for DescriptionEngine {
 
         vec![]
     }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {}
-
-    fn replace_graphics(&mut self, graphics: crate::graphics::Graphics) {
-        self.ui_description.graphics = graphics;
-    }
 }
@@ -23,8 +23,4 @@ impl Engine for ErrorEngine {
 
         vec![]
     }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {}
-
-    fn replace_graphics(&mut self, _graphics: crate::graphics::Graphics) {}
 }
@@ -1,4 +1,4 @@
-use crate::{client, description, message};
+use crate::{client, description, i18n, message};
 
 pub struct LoadDescriptionEngine {
     pub request: quad_net::http_request::Request,
@@ -61,15 +61,11 @@ impl super::Engine for LoadDescriptionEngine {
         // UI
         egui_macroquad::ui(|egui_ctx| {
             egui::CentralPanel::default().show(&egui_ctx, |ui| {
-                ui.colored_label(egui::Color32::LIGHT_GRAY, "Chargement ...");
+                ui.colored_label(egui::Color32::LIGHT_GRAY, i18n::tr("loading"));
             });
         });
         egui_macroquad::draw();
 
         vec![]
     }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {}
-
-    fn replace_graphics(&mut self, _graphics: crate::graphics::Graphics) {}
 }
@@ -2,7 +2,9 @@ use macroquad::prelude::*;
 use quad_net::http_request::Request;
 use serde_json::Value;
 
-use crate::{client, engine::dead::CheckCharacterIsDeadEngine, entity, hardcoded, message, zone};
+use crate::{
+    client, engine::dead::CheckCharacterIsDeadEngine, entity, hardcoded, i18n, message, zone,
+};
 
 use super::Engine;
 
@@ -432,15 +434,11 @@ impl Engine for LoadZoneEngine {
 
         egui_macroquad::ui(|egui_ctx| {
             egui::CentralPanel::default().show(&egui_ctx, |ui| {
-                ui.colored_label(egui::Color32::LIGHT_GRAY, "Chargement ...");
+                ui.colored_label(egui::Color32::LIGHT_GRAY, i18n::tr("loading"));
             });
         });
         egui_macroquad::draw();
 
         messages
     }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {}
-
-    fn replace_graphics(&mut self, _graphics: crate::graphics::Graphics) {}
 }
@@ -1,4 +1,4 @@
-use crate::{graphics, message};
+use crate::message;
 
 pub mod dead;
 pub mod description;
@@ -11,6 +11,4 @@ pub mod zone;
 
 pub trait Engine {
     fn tick(&mut self) -> Vec<message::MainMessage>;
-    fn replace_graphics(&mut self, graphics: graphics::Graphics);
-    fn signal_illustration_loaded(&mut self, illustration_name: &str);
 }
@@ -2,7 +2,7 @@ use crate::{
     client::{self, Client},
     engine::root::util::auth_failed,
     graphics::Graphics,
-    message,
+    i18n, message,
     ui::utils::is_mobile,
     util::{get_auth_token, get_remember_me, set_auth_token, set_remember_me},
 };
@@ -63,7 +63,8 @@ impl RootScene {
                 match auth_failed(&data) {
                     Ok(auth_failed_) => {
                         if auth_failed_ {
-                            self.state.error_message = Some("Authentification échoué".to_string());
+                            self.state.error_message =
+                                Some(i18n::tr("root.auth_failed").to_string());
                         } else {
                             let auth_token = &data.unwrap_or("".to_string());
                             set_auth_token(Some(auth_token));
@@ -74,7 +75,8 @@ impl RootScene {
                         }
                     }
                     Err(error) => {
-                        self.state.error_message = Some(format!("Erreur : {}", error));
+                        self.state.error_message =
+                            Some(format!("{} : {}", i18n::tr("error"), error));
                     }
                 }
                 self.do_get_auth_token_request = None;
@@ -86,7 +88,8 @@ impl RootScene {
                 match auth_failed(&data) {
                     Ok(auth_failed_) => {
                         if auth_failed_ {
-                            self.state.error_message = Some("Authentification échoué".to_string());
+                            self.state.error_message =
+                                Some(i18n::tr("root.auth_failed").to_string());
                         } else {
                             let character_id = &data.unwrap_or("".to_string());
                             if character_id == "" {
@@ -108,7 +111,8 @@ impl RootScene {
                         }
                     }
                     Err(error) => {
-                        self.state.error_message = Some(format!("Erreur : {}", error));
+                        self.state.error_message =
+                            Some(format!("{} : {}", i18n::tr("error"), error));
                     }
                 }
 
@@ -123,7 +127,8 @@ impl RootScene {
                     Ok(auth_failed_) => {
                         if auth_failed_ {
                             set_auth_token(None);
-                            self.state.error_message = Some("Authentification échoué".to_string());
+                            self.state.error_message =
+                                Some(i18n::tr("root.auth_failed").to_string());
                         } else {
                             let character_id = &data.unwrap_or("".to_string());
                             let auth_token = get_auth_token()
@@ -141,7 +146,8 @@ impl RootScene {
                         }
                     }
                     Err(error) => {
-                        self.state.error_message = Some(format!("Erreur : {}", error));
+                        self.state.error_message =
+                            Some(format!("{} : {}", i18n::tr("error"), error));
                     }
                 }
 
@@ -310,10 +316,6 @@ impl Engine for RootScene {
         self.state.first_frame = false;
         messages
     }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {}
-
-    fn replace_graphics(&mut self, _graphics: crate::graphics::Graphics) {}
 }
 
 #[derive(Debug)]
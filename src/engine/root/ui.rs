@@ -3,7 +3,12 @@ use macroquad::prelude::*;
 use crate::{
     engine::root::state,
     graphics::Graphics,
-    ui::utils::{egui_scale, is_mobile},
+    i18n,
+    settings::Settings,
+    ui::{
+        language::language_selector,
+        utils::{egui_scale, is_mobile},
+    },
     util::vname,
 };
 use egui::{self, TextureFilter};
@@ -18,12 +23,9 @@ pub fn ui(state: &mut state::RootState, graphics: &Graphics) -> Option<super::Ro
             style.override_text_style = Some(egui::TextStyle::Heading);
             egui_ctx.set_style(style);
 
-            if let Some(image_data) = graphics.illustrations.get(&vname("root.png")) {
-                let texture: egui::TextureHandle = egui_ctx.load_texture(
-                    vname("root.png"),
-                    image_data.clone(),
-                    TextureFilter::Linear,
-                );
+            if let Some(image_data) = graphics.illustration(&vname("root.png")) {
+                let texture: egui::TextureHandle =
+                    egui_ctx.load_texture(vname("root.png"), image_data, TextureFilter::Linear);
                 state.root_illustration = Some(texture);
             }
         }
@@ -36,12 +38,12 @@ pub fn ui(state: &mut state::RootState, graphics: &Graphics) -> Option<super::Ro
             });
         }
 
-        egui::Window::new("Se connecter / Créer un compte")
+        egui::Window::new(i18n::tr("root.title"))
             .resizable(false)
             .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0., -50.))
             .show(egui_ctx, |ui| {
                 if state.loading {
-                    ui.colored_label(egui::Color32::LIGHT_GRAY, "Chargement ...");
+                    ui.colored_label(egui::Color32::LIGHT_GRAY, i18n::tr("loading"));
                 } else {
                     if let Some(error_message) = &state.error_message {
                         ui.colored_label(egui::Color32::RED, error_message);
@@ -53,7 +55,7 @@ pub fn ui(state: &mut state::RootState, graphics: &Graphics) -> Option<super::Ro
                 }
 
                 ui.horizontal(|ui| {
-                    ui.label("Login: ");
+                    ui.label(i18n::tr("root.login"));
                     let login_input = ui.text_edit_singleline(&mut state.login);
 
                     if state.first_frame && !is_mobile() {
@@ -68,7 +70,7 @@ pub fn ui(state: &mut state::RootState, graphics: &Graphics) -> Option<super::Ro
                 });
 
                 ui.horizontal(|ui| {
-                    ui.label("Mot de passe: ");
+                    ui.label(i18n::tr("root.password"));
                     if ui
                         .add(egui::TextEdit::singleline(&mut state.password).password(true))
                         .gained_focus()
@@ -80,20 +82,29 @@ pub fn ui(state: &mut state::RootState, graphics: &Graphics) -> Option<super::Ro
                 });
 
                 ui.horizontal(|ui| {
-                    ui.checkbox(&mut state.remember_me, "Rester connecté");
+                    ui.checkbox(&mut state.remember_me, i18n::tr("root.remember_me"));
                 });
 
                 ui.horizontal(|ui| {
-                    if ui.button("Se connecter").clicked() {
+                    if ui.button(i18n::tr("root.log_in")).clicked() {
                         event = Some(super::RootEvent::DoLoginWithCredentials);
                     }
 
-                    if ui.button("Mot de passe perdu").clicked() {
+                    if ui.button(i18n::tr("root.password_lost")).clicked() {
                         event = Some(super::RootEvent::GoToPasswordLost);
                     }
-                    if ui.button("Créer un compte").clicked() {
+                    if ui.button(i18n::tr("root.create_account")).clicked() {
                         event = Some(super::RootEvent::GoToCreateAccount);
                     }
+                });
+
+                ui.horizontal(|ui| {
+                    let mut language = i18n::language();
+                    if language_selector(ui, &mut language) {
+                        let mut settings = Settings::load();
+                        settings.language = language;
+                        settings.save();
+                    }
                 })
             });
     });
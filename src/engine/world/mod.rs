@@ -5,6 +5,7 @@ use crate::{
     client::Client,
     entity::{character::Character, world::WorldAsCharacter},
     graphics::Graphics,
+    i18n,
     message::MainMessage,
     ui::utils::is_mobile,
     util::mouse_clicked,
@@ -85,7 +86,7 @@ impl WorldEngine {
 
     fn loading(&mut self) -> Vec<MainMessage> {
         draw_text(
-            "Chargement ...",
+            i18n::tr("loading"),
             (screen_width() / 2.) - 128.,
             screen_height() / 2.,
             64.,
@@ -103,12 +104,15 @@ impl WorldEngine {
                 for (col_i, tile_id) in row.iter().enumerate() {
                     let dest_x = col_i as f32 * self.graphics.tile_width;
 
-                    let source = self.graphics.tiles_mapping.get(tile_id).unwrap().to_rect(0);
-                    draw_texture_ex(
-                        self.graphics.tileset_texture,
+                    let source = self
+                        .graphics
+                        .tiles_mapping()
+                        .get(tile_id)
+                        .unwrap()
+                        .to_rect(0);
+                    self.graphics.draw_tileset_part(
                         dest_x,
                         dest_y,
-                        WHITE,
                         DrawTextureParams {
                             source: Some(source),
                             ..Default::default()
@@ -123,15 +127,13 @@ impl WorldEngine {
             let dest_x = self.player.world_col_i as f32 * self.graphics.tile_width;
             let dest_y = self.player.world_row_i as f32 * self.graphics.tile_height;
 
-            draw_texture_ex(
-                self.graphics.tileset_texture,
+            self.graphics.draw_tileset_part(
                 dest_x,
                 dest_y,
-                WHITE,
                 DrawTextureParams {
                     source: Some(
                         self.graphics
-                            .tiles_mapping
+                            .tiles_mapping()
                             .get("CHARACTER")
                             .unwrap()
                             .to_rect(0),
@@ -152,11 +154,9 @@ impl WorldEngine {
         };
         let draw_back_button_to_x: f32 = screen_width() - dest_size.x - 25.0;
         let draw_back_button_to_y: f32 = 25.0;
-        draw_texture_ex(
-            self.graphics.tileset_texture,
+        self.graphics.draw_tileset_part(
             draw_back_button_to_x,
             draw_back_button_to_y,
-            WHITE,
             DrawTextureParams {
                 source: Some(Rect::new(
                     BACK_BUTTON_START_X,
@@ -208,10 +208,4 @@ impl Engine for WorldEngine {
 
         messages
     }
-
-    fn replace_graphics(&mut self, graphics: Graphics) {
-        self.graphics = graphics;
-    }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {}
 }
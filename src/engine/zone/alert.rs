@@ -0,0 +1,282 @@
+use egui::Context;
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{i18n, storage};
+
+use super::resume::{CharacterResume, Health};
+
+const ALERTS_WINDOW_WIDTH: f32 = 300.;
+const BANNER_COLOR: egui::Color32 = egui::Color32::from_rgb(160, 30, 30);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Need {
+    Hunger,
+    Thirst,
+    Tiredness,
+    Health,
+}
+
+impl Need {
+    pub fn name(&self) -> &str {
+        match self {
+            Need::Hunger => i18n::tr("resume.hunger"),
+            Need::Thirst => i18n::tr("resume.thirst"),
+            Need::Tiredness => i18n::tr("resume.tiredness"),
+            Need::Health => i18n::tr("alert.health"),
+        }
+    }
+}
+
+/// Thresholds (bar level, as displayed, under which alert is raised), per character
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct NeedAlertsSettings {
+    pub hunger: Option<f32>,
+    pub thirst: Option<f32>,
+    pub tiredness: Option<f32>,
+    // Alert when health is this level or worse
+    pub health: Option<Health>,
+    // Minutes before a snoozed banner is displayed again
+    pub snooze_minutes: f32,
+}
+
+impl Default for NeedAlertsSettings {
+    fn default() -> Self {
+        Self {
+            hunger: Some(20.),
+            thirst: Some(20.),
+            tiredness: Some(20.),
+            health: Some(Health::Bad),
+            snooze_minutes: 10.,
+        }
+    }
+}
+
+fn storage_key(character_id: &str) -> String {
+    format!("__NEED_ALERTS__{}", character_id)
+}
+
+impl NeedAlertsSettings {
+    pub fn load(character_id: &str) -> Self {
+        storage::load_json(&storage_key(character_id)).unwrap_or_default()
+    }
+
+    pub fn save(&self, character_id: &str) {
+        storage::save_json(&storage_key(character_id), self)
+    }
+}
+
+pub enum NeedAlertsEvent {
+    OpenSettings,
+}
+
+/// Alerts raised when character needs cross their thresholds
+pub struct NeedAlerts {
+    character_id: String,
+    settings: NeedAlertsSettings,
+    // Needs currently under their threshold, with alert message
+    active: Vec<(Need, String)>,
+    snoozed_until: f64,
+    display_settings: bool,
+    mouse_hover: bool,
+}
+
+impl NeedAlerts {
+    pub fn new(character_id: &str) -> Self {
+        Self {
+            character_id: character_id.to_string(),
+            settings: NeedAlertsSettings::load(character_id),
+            active: vec![],
+            snoozed_until: 0.,
+            display_settings: false,
+            mouse_hover: false,
+        }
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    pub fn is_display_settings(&self) -> bool {
+        self.display_settings
+    }
+
+    pub fn set_display_settings(&mut self, display: bool) {
+        self.display_settings = display;
+    }
+
+    /// Update active alerts from resume and return messages of newly raised alerts
+    pub fn check(&mut self, resume: &CharacterResume) -> Vec<String> {
+        let mut active = vec![];
+        let bars = [
+            (Need::Hunger, self.settings.hunger, resume.hungry.level()),
+            (Need::Thirst, self.settings.thirst, resume.thirsty.level()),
+            (
+                Need::Tiredness,
+                self.settings.tiredness,
+                resume.tiredness.level(),
+            ),
+        ];
+        for (need, threshold, level) in bars {
+            if let Some(threshold) = threshold {
+                if level <= threshold {
+                    active.push((
+                        need,
+                        format!(
+                            "{} : {:.0} % ({} {:.0} %)",
+                            need.name(),
+                            level,
+                            i18n::tr("alert.threshold"),
+                            threshold
+                        ),
+                    ));
+                }
+            }
+        }
+        if let Some(health_threshold) = &self.settings.health {
+            if resume.health.severity() >= health_threshold.severity() {
+                active.push((
+                    Need::Health,
+                    format!("{} : {}", Need::Health.name(), resume.health.name()),
+                ));
+            }
+        }
+
+        let raised = active
+            .iter()
+            .filter(|(need, _)| !self.active.iter().any(|(known, _)| known == need))
+            .map(|(_, message)| message.clone())
+            .collect::<Vec<String>>();
+        // A new alert must be seen even if banner is snoozed
+        if !raised.is_empty() {
+            self.snoozed_until = 0.;
+        }
+        self.active = active;
+
+        raised
+    }
+
+    pub fn snooze(&mut self) {
+        self.snoozed_until = get_time() + self.settings.snooze_minutes as f64 * 60.;
+    }
+
+    /// Banner (at screen top) listing active alerts
+    pub fn banner_ui(&mut self, egui_ctx: &Context) -> Option<NeedAlertsEvent> {
+        self.mouse_hover = false;
+        if self.active.is_empty() || get_time() < self.snoozed_until {
+            return None;
+        }
+
+        let mut event = None;
+        let mut snooze = false;
+        let response = egui::Area::new("need_alerts_banner")
+            .anchor(egui::Align2::CENTER_TOP, (0., 5.))
+            .show(egui_ctx, |ui| {
+                egui::Frame::popup(ui.style())
+                    .fill(BANNER_COLOR)
+                    .show(ui, |ui| {
+                        for (_, message) in &self.active {
+                            ui.colored_label(egui::Color32::WHITE, format!("⚠ {}", message));
+                        }
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(format!(
+                                    "{} {:.0} min",
+                                    i18n::tr("alert.snooze"),
+                                    self.settings.snooze_minutes
+                                ))
+                                .clicked()
+                            {
+                                snooze = true;
+                            }
+                            if ui.button(i18n::tr("alert.settings")).clicked() {
+                                event = Some(NeedAlertsEvent::OpenSettings);
+                            }
+                        });
+                    });
+            });
+
+        self.mouse_hover = response.response.hovered();
+        if snooze {
+            self.snooze();
+        }
+
+        event
+    }
+
+    pub fn settings_ui(&mut self, egui_ctx: &Context) {
+        let mut display = self.display_settings;
+        let mut changed = false;
+
+        let response = egui::Window::new(i18n::tr("alert.settings_title"))
+            .open(&mut display)
+            .default_width(ALERTS_WINDOW_WIDTH)
+            .show(egui_ctx, |ui| {
+                ui.label(i18n::tr("alert.when_below"));
+                for (need, threshold) in [
+                    (Need::Hunger, &mut self.settings.hunger),
+                    (Need::Thirst, &mut self.settings.thirst),
+                    (Need::Tiredness, &mut self.settings.tiredness),
+                ] {
+                    ui.horizontal(|ui| {
+                        let mut enabled = threshold.is_some();
+                        if ui.checkbox(&mut enabled, need.name()).changed() {
+                            *threshold = if enabled { Some(20.) } else { None };
+                            changed = true;
+                        }
+                        if let Some(value) = threshold {
+                            changed |= ui
+                                .add(egui::Slider::new(value, 0. ..=100.).suffix(" %"))
+                                .changed();
+                        }
+                    });
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(Need::Health.name());
+                    let selected = match &self.settings.health {
+                        Some(health) => format!("{} {}", health.name(), i18n::tr("alert.or_worse")),
+                        None => i18n::tr("alert.never").to_string(),
+                    };
+                    egui::ComboBox::from_id_source("need_alerts_health")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut self.settings.health,
+                                    None,
+                                    i18n::tr("alert.never"),
+                                )
+                                .changed();
+                            for health in [Health::Middle, Health::Bad, Health::Critical] {
+                                let text =
+                                    format!("{} {}", health.name(), i18n::tr("alert.or_worse"));
+                                changed |= ui
+                                    .selectable_value(&mut self.settings.health, Some(health), text)
+                                    .changed();
+                            }
+                        });
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("alert.remind_after"));
+                    changed |= ui
+                        .add(
+                            egui::Slider::new(&mut self.settings.snooze_minutes, 1. ..=120.)
+                                .suffix(" min"),
+                        )
+                        .changed();
+                });
+            });
+
+        if changed {
+            self.settings.save(&self.character_id);
+        }
+        self.mouse_hover |= match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        self.display_settings = display;
+    }
+}
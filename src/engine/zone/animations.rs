@@ -1,12 +1,14 @@
+use macroquad::prelude::*;
+
 impl super::ZoneEngine {
-    pub fn camera_animations(&mut self) {
+    /// Update camera animations (they are drawn by the scene, in overlays layer)
+    pub fn update_camera_animations(&mut self) {
         let mut to_removes: Vec<usize> = vec![];
 
         for (i, animation) in self.camera_animations.iter_mut().enumerate() {
-            if animation.update(self.frame_i) {
+            if animation.update(get_time()) {
                 to_removes.push(i);
             }
-            animation.draw_in_camera(&self.graphics);
         }
 
         // Remove finished animations
@@ -21,7 +23,7 @@ impl super::ZoneEngine {
         let mut to_removes: Vec<usize> = vec![];
 
         for (i, animation) in self.ui_animations.iter_mut().enumerate() {
-            if animation.update(self.frame_i) {
+            if animation.update(get_time()) {
                 to_removes.push(i);
             }
             animation.draw_in_camera(&self.graphics);
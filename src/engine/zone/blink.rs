@@ -21,11 +21,9 @@ impl ZoneEngine {
             draw_rectangle(dest.x, dest.y, ICON_WIDTH, ICON_HEIGHT, LIGHTGRAY);
 
             if blinking_icon.visible() {
-                draw_texture_ex(
-                    self.graphics.tileset_texture,
+                self.graphics.draw_tileset_part(
                     dest.x,
                     dest.y,
-                    WHITE,
                     DrawTextureParams {
                         dest_size: Some(Vec2::new(ICON_WIDTH, ICON_HEIGHT)),
                         source: Some(blinking_icon.source()),
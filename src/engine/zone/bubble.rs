@@ -0,0 +1,189 @@
+use macroquad::prelude::*;
+
+use crate::animation::{easing::Easing, tween::Tween};
+
+use super::ZoneEngine;
+
+const BUBBLE_FONT_SIZE: f32 = 18.;
+const BUBBLE_MAX_TEXT_WIDTH: f32 = 200.;
+const BUBBLE_PADDING: f32 = 6.;
+const BUBBLE_MARGIN: f32 = 4.;
+const BUBBLE_BACKGROUND_COLOR: Color = Color::new(1.0, 1.0, 1.0, 0.9);
+const BUBBLE_TEXT_COLOR: Color = Color::new(0.1, 0.1, 0.1, 1.0);
+// Seconds a bubble stay displayed, longer for longer messages
+const BUBBLE_MIN_DURATION: f32 = 4.;
+const BUBBLE_MAX_DURATION: f32 = 10.;
+const BUBBLE_DURATION_PER_CHAR: f32 = 0.06;
+const BUBBLE_FADE_DURATION: f32 = 1.;
+// Older bubbles of a character are removed beyond this count
+const MAX_BUBBLES_PER_CHARACTER: usize = 3;
+
+pub struct SpeechBubble {
+    character_id: String,
+    lines: Vec<String>,
+    created: f64,
+    duration: f32,
+}
+
+impl SpeechBubble {
+    fn alpha(&self, now: f64) -> f32 {
+        let elapsed = (now - self.created) as f32;
+        Tween::new(1., 0., BUBBLE_FADE_DURATION, Easing::QuadIn)
+            .value_at(elapsed - (self.duration - BUBBLE_FADE_DURATION))
+    }
+
+    fn is_expired(&self, now: f64) -> bool {
+        (now - self.created) as f32 >= self.duration
+    }
+}
+
+/// Chat messages displayed above speaking characters
+#[derive(Default)]
+pub struct SpeechBubbles {
+    bubbles: Vec<SpeechBubble>,
+}
+
+impl SpeechBubbles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, character_id: &str, message: &str) {
+        let duration = (message.chars().count() as f32 * BUBBLE_DURATION_PER_CHAR)
+            .clamp(BUBBLE_MIN_DURATION, BUBBLE_MAX_DURATION);
+        self.bubbles.push(SpeechBubble {
+            character_id: character_id.to_string(),
+            lines: wrap_text(message, BUBBLE_MAX_TEXT_WIDTH, BUBBLE_FONT_SIZE as u16),
+            created: get_time(),
+            duration,
+        });
+
+        let character_bubbles = self
+            .bubbles
+            .iter()
+            .filter(|bubble| bubble.character_id == character_id)
+            .count();
+        if character_bubbles > MAX_BUBBLES_PER_CHARACTER {
+            if let Some(oldest_i) = self
+                .bubbles
+                .iter()
+                .position(|bubble| bubble.character_id == character_id)
+            {
+                self.bubbles.remove(oldest_i);
+            }
+        }
+    }
+
+    pub fn remove_expired(&mut self, now: f64) {
+        self.bubbles.retain(|bubble| !bubble.is_expired(now));
+    }
+}
+
+/// Split text in lines not wider than given width (a too long word stay on its own line)
+fn wrap_text(text: &str, max_width: f32, font_size: u16) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", line, word)
+        };
+        if !line.is_empty() && measure_text(&candidate, None, font_size, 1.0).width > max_width {
+            lines.push(line);
+            line = word.to_string();
+        } else {
+            line = candidate;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+impl ZoneEngine {
+    pub fn add_speech_bubble(&mut self, character_id: &str, message: &str) {
+        self.speech_bubbles.add(character_id, message);
+    }
+
+    /// Screen position of the top center of the character (above its name)
+    fn speech_bubble_anchor(&self, character_id: &str) -> Option<Vec2> {
+        let zoom_factor = self.camera.zoom();
+        let tile_width = self.graphics.tile_width * zoom_factor;
+        let tile_height = self.graphics.tile_height * zoom_factor;
+
+        let screen_position = if character_id == self.state.player.id {
+            self.concrete_position_to_screen_position(self.state.player_display.draw_position())
+        } else {
+            let character = self.state.characters.get(character_id)?;
+            self.zone_position_to_screen_position(
+                character.zone_row_i as f32,
+                character.zone_col_i as f32,
+            )
+        };
+
+        // Tiles are drawn one tile upper, and name is drawn above
+        Some(Vec2::new(
+            screen_position.x + tile_width / 2.,
+            screen_position.y - tile_height * 1.5 - 24.,
+        ))
+    }
+
+    pub fn draw_speech_bubbles(&mut self) {
+        let now = get_time();
+        self.speech_bubbles.remove_expired(now);
+        if !self.settings.speech_bubbles {
+            return;
+        }
+
+        // Newest bubbles are drawn nearest to the character
+        let mut characters_bottoms: Vec<(&str, f32)> = vec![];
+        for bubble in self.speech_bubbles.bubbles.iter().rev() {
+            let anchor = match self.speech_bubble_anchor(&bubble.character_id) {
+                Some(anchor) => anchor,
+                None => continue,
+            };
+            let bottom = match characters_bottoms
+                .iter_mut()
+                .find(|(character_id, _)| *character_id == bubble.character_id)
+            {
+                Some((_, bottom)) => bottom,
+                None => {
+                    characters_bottoms.push((&bubble.character_id, anchor.y));
+                    &mut characters_bottoms.last_mut().expect("Just pushed").1
+                }
+            };
+
+            let text_width = bubble
+                .lines
+                .iter()
+                .map(|line| measure_text(line, None, BUBBLE_FONT_SIZE as u16, 1.0).width)
+                .fold(0., f32::max);
+            let width = text_width + BUBBLE_PADDING * 2.;
+            let height = BUBBLE_FONT_SIZE * bubble.lines.len() as f32 + BUBBLE_PADDING * 2.;
+            let x = anchor.x - width / 2.;
+            let y = *bottom - height;
+            *bottom = y - BUBBLE_MARGIN;
+
+            let alpha = bubble.alpha(now);
+            let mut background_color = BUBBLE_BACKGROUND_COLOR;
+            background_color.a *= alpha;
+            let mut text_color = BUBBLE_TEXT_COLOR;
+            text_color.a *= alpha;
+
+            draw_rectangle(x, y, width, height, background_color);
+            for (i, line) in bubble.lines.iter().enumerate() {
+                draw_text(
+                    line,
+                    x + BUBBLE_PADDING,
+                    y + BUBBLE_PADDING + BUBBLE_FONT_SIZE * (i as f32 + 0.8),
+                    BUBBLE_FONT_SIZE,
+                    text_color,
+                );
+            }
+        }
+    }
+}
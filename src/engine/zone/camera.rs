@@ -0,0 +1,201 @@
+use macroquad::prelude::*;
+
+use crate::{settings::Settings, ui::utils::is_mobile};
+
+// Proportion of remaining distance covered per second when following the player
+const FOLLOW_SPEED: f32 = 8.0;
+// Screen pixels per second when moving camera with keyboard in free look mode
+const FREE_LOOK_KEYBOARD_SPEED: f32 = 600.0;
+const ZOOM_WHEEL_STEP: f32 = 1.1;
+const ZOOM_KEYBOARD_SPEED: f32 = 1.5;
+// Not a letter, letters can be given to quick actions by server
+pub const FREE_LOOK_KEY: KeyCode = KeyCode::F2;
+
+pub struct Camera {
+    // Screen pixels per zone concrete pixel
+    zoom: f32,
+    default_zoom: f32,
+    min_zoom: f32,
+    max_zoom: f32,
+    // Zone concrete position displayed at screen center
+    position: Vec2,
+    free_look: bool,
+    last_drag_position: Option<Vec2>,
+    last_pinch_distance: Option<f32>,
+}
+
+impl Camera {
+    pub fn new(position: Vec2, settings: &Settings) -> Self {
+        let mut camera = Self {
+            zoom: 1.0,
+            default_zoom: 1.0,
+            min_zoom: 1.0,
+            max_zoom: 1.0,
+            position,
+            free_look: false,
+            last_drag_position: None,
+            last_pinch_distance: None,
+        };
+        camera.apply_settings(settings);
+        camera.zoom = camera.default_zoom;
+        camera
+    }
+
+    /// Update zoom bounds from (possibly modified) settings
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.min_zoom = settings.camera_min_zoom.min(settings.camera_max_zoom);
+        self.max_zoom = settings.camera_max_zoom.max(settings.camera_min_zoom);
+        let default_zoom: f32 = if is_mobile() { 3.0 } else { 1.0 };
+        self.default_zoom = default_zoom.clamp(self.min_zoom, self.max_zoom);
+        self.zoom = self.zoom.clamp(self.min_zoom, self.max_zoom);
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn is_zoomed_in(&self) -> bool {
+        self.zoom > self.default_zoom
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    pub fn is_free_look(&self) -> bool {
+        self.free_look
+    }
+
+    pub fn set_free_look(&mut self, free_look: bool) {
+        self.free_look = free_look;
+        self.last_drag_position = None;
+    }
+
+    pub fn is_pinching(&self) -> bool {
+        self.last_pinch_distance.is_some()
+    }
+
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.zoom = (self.zoom * factor).clamp(self.min_zoom, self.max_zoom);
+    }
+
+    pub fn reset_zoom(&mut self) {
+        self.zoom = self.default_zoom;
+    }
+
+    pub fn zoom_in_max(&mut self) {
+        self.zoom = self.max_zoom;
+    }
+
+    pub fn center_on(&mut self, position: Vec2) {
+        self.position = position;
+    }
+
+    /// Move the camera by given screen pixels
+    pub fn move_by(&mut self, screen_delta: Vec2) {
+        self.position += screen_delta / self.zoom;
+    }
+
+    /// Zoom factor to apply from mouse wheel and keyboard for this frame
+    pub fn zoom_input(&self) -> Option<f32> {
+        let (_, wheel_y) = mouse_wheel();
+        if wheel_y > 0. {
+            return Some(ZOOM_WHEEL_STEP);
+        } else if wheel_y < 0. {
+            return Some(1. / ZOOM_WHEEL_STEP);
+        }
+
+        let keyboard_step = 1. + ZOOM_KEYBOARD_SPEED * get_frame_time();
+        if is_key_down(KeyCode::I) {
+            return Some(keyboard_step);
+        }
+        if is_key_down(KeyCode::O) {
+            return Some(1. / keyboard_step);
+        }
+
+        None
+    }
+
+    /// Zoom factor to apply from two fingers pinch for this frame
+    pub fn pinch_input(&mut self) -> Option<f32> {
+        let touches = touches();
+        if touches.len() != 2 {
+            self.last_pinch_distance = None;
+            return None;
+        }
+
+        let distance = touches[0].position.distance(touches[1].position);
+        let factor = self
+            .last_pinch_distance
+            .filter(|last_distance| *last_distance > 0.)
+            .map(|last_distance| distance / last_distance);
+        self.last_pinch_distance = Some(distance);
+        factor
+    }
+
+    /// Screen pixels move to apply from drag and arrow keys for this frame (free look only)
+    pub fn free_look_input(&mut self) -> Vec2 {
+        let mut move_ = Vec2::new(0., 0.);
+        if !self.free_look {
+            return move_;
+        }
+
+        let keyboard_step = FREE_LOOK_KEYBOARD_SPEED * get_frame_time();
+        if is_key_down(KeyCode::Up) || is_key_down(KeyCode::Z) || is_key_down(KeyCode::W) {
+            move_.y -= keyboard_step;
+        }
+        if is_key_down(KeyCode::Down) || is_key_down(KeyCode::S) {
+            move_.y += keyboard_step;
+        }
+        if is_key_down(KeyCode::Left) || is_key_down(KeyCode::Q) || is_key_down(KeyCode::A) {
+            move_.x -= keyboard_step;
+        }
+        if is_key_down(KeyCode::Right) || is_key_down(KeyCode::D) {
+            move_.x += keyboard_step;
+        }
+
+        // Pinch must not be considered as drag
+        if is_mouse_button_down(drag_button()) && self.last_pinch_distance.is_none() {
+            let mouse_position = Vec2::from(mouse_position());
+            if let Some(last_drag_position) = self.last_drag_position {
+                move_ += last_drag_position - mouse_position;
+            }
+            self.last_drag_position = Some(mouse_position);
+        } else {
+            self.last_drag_position = None;
+        }
+
+        move_
+    }
+
+    /// Follow the target (if not in free look) and keep camera inside given zone bounds
+    pub fn update(&mut self, target: Vec2, bounds: Rect) {
+        if !self.free_look {
+            let ease = (FOLLOW_SPEED * get_frame_time()).min(1.);
+            self.position += (target - self.position) * ease;
+        }
+
+        let half_width = (screen_width() / 2.) / self.zoom;
+        let half_height = (screen_height() / 2.) / self.zoom;
+        self.position.x = clamp_axis(self.position.x, bounds.x, bounds.w, half_width);
+        self.position.y = clamp_axis(self.position.y, bounds.y, bounds.h, half_height);
+    }
+}
+
+/// Left button is used for clicks on zone and interface, so drag with middle one (touch on mobile)
+fn drag_button() -> MouseButton {
+    if is_mobile() {
+        MouseButton::Left
+    } else {
+        MouseButton::Middle
+    }
+}
+
+fn clamp_axis(value: f32, start: f32, size: f32, half_visible: f32) -> f32 {
+    // Zone is smaller than screen : keep it centered
+    if size <= half_visible * 2. {
+        return start + size / 2.;
+    }
+
+    value.clamp(start + half_visible, start + size - half_visible)
+}
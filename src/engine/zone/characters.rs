@@ -0,0 +1,231 @@
+use ahash::AHashMap;
+use egui::{Context, TextureFilter};
+use macroquad::prelude::*;
+use quad_net::http_request::Request;
+
+use crate::{client::Client, entity::character::Character, i18n, types::AvatarUuid};
+
+use super::state::ZoneState;
+
+const CHARACTERS_WINDOW_WIDTH: f32 = 300.;
+const CHARACTERS_WINDOW_HEIGHT: f32 = 300.;
+const AVATAR_THUMB_SIZE: f32 = 24.;
+
+#[derive(PartialEq, Default)]
+pub enum CharactersSort {
+    Name,
+    #[default]
+    Distance,
+}
+
+pub enum CharactersPanelEvent {
+    CenterCameraOn(String),
+    OpenCard(String),
+    Follow(String),
+    StopFollow,
+}
+
+#[derive(Default)]
+pub struct CharactersPanel {
+    display: bool,
+    sort: CharactersSort,
+    mouse_hover: bool,
+    avatar_requests: AHashMap<AvatarUuid, Request>,
+    // None when avatar can't be loaded
+    avatars: AHashMap<AvatarUuid, Option<egui::TextureHandle>>,
+}
+
+impl CharactersPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_display(&self) -> bool {
+        self.display
+    }
+
+    pub fn set_display(&mut self, display: bool) {
+        self.display = display;
+        if !display {
+            self.mouse_hover = false;
+        }
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    /// Request avatars not already known (only when panel is displayed, to keep it lazy)
+    fn require_avatars(&mut self, client: &Client, characters: &Vec<&Character>) {
+        for character in characters {
+            let avatar_uuid = character.public_avatar_uuid();
+            if !self.avatars.contains_key(&avatar_uuid)
+                && !self.avatar_requests.contains_key(&avatar_uuid)
+            {
+                let request = client.get_avatar_zone_thumb_request(&avatar_uuid);
+                self.avatar_requests.insert(avatar_uuid, request);
+            }
+        }
+    }
+
+    fn proceed_avatar_requests(&mut self, egui_ctx: &Context) {
+        let mut finished = vec![];
+
+        for (avatar_uuid, request) in self.avatar_requests.iter_mut() {
+            if let Some(data) = request.try_recv() {
+                let texture = match data {
+                    Ok(image_data) => match image::load_from_memory(image_data.as_bytes()) {
+                        Ok(image_) => {
+                            let image_data = egui::ColorImage::from_rgba_unmultiplied(
+                                [image_.width() as usize, image_.height() as usize],
+                                &image_.to_rgba8(),
+                            );
+                            Some(egui_ctx.load_texture(
+                                format!("avatar_zone_thumb__{}", avatar_uuid),
+                                image_data,
+                                TextureFilter::Linear,
+                            ))
+                        }
+                        Err(error) => {
+                            error!("Unable to decode avatar {} : {}", avatar_uuid, error);
+                            None
+                        }
+                    },
+                    Err(error) => {
+                        error!("Error while requiring avatar {} : {}", avatar_uuid, error);
+                        None
+                    }
+                };
+                self.avatars.insert(avatar_uuid.clone(), texture);
+                finished.push(avatar_uuid.clone());
+            }
+        }
+
+        for avatar_uuid in finished {
+            self.avatar_requests.remove(&avatar_uuid);
+        }
+    }
+
+    pub fn ui(
+        &mut self,
+        egui_ctx: &Context,
+        client: &Client,
+        state: &ZoneState,
+        followed_character_id: &Option<String>,
+    ) -> Option<CharactersPanelEvent> {
+        let mut event = None;
+        let mut display = self.display;
+
+        let player_row_i = state.player.zone_row_i;
+        let player_col_i = state.player.zone_col_i;
+        let distance = |character: &Character| -> f32 {
+            let row_diff = (character.zone_row_i - player_row_i) as f32;
+            let col_diff = (character.zone_col_i - player_col_i) as f32;
+            (row_diff * row_diff + col_diff * col_diff).sqrt()
+        };
+
+        let mut characters: Vec<&Character> = state.characters.values().collect();
+        match self.sort {
+            CharactersSort::Name => {
+                characters.sort_by_key(|character| character.name.to_lowercase())
+            }
+            CharactersSort::Distance => {
+                characters.sort_by(|a, b| distance(a).total_cmp(&distance(b)))
+            }
+        }
+
+        self.require_avatars(client, &characters);
+        self.proceed_avatar_requests(egui_ctx);
+
+        let response = egui::Window::new(i18n::tr("characters.title"))
+            .open(&mut display)
+            .default_pos((0., 0.))
+            .default_size((CHARACTERS_WINDOW_WIDTH, CHARACTERS_WINDOW_HEIGHT))
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("characters.sort_by"));
+                    ui.radio_value(
+                        &mut self.sort,
+                        CharactersSort::Distance,
+                        i18n::tr("characters.sort_distance"),
+                    );
+                    ui.radio_value(
+                        &mut self.sort,
+                        CharactersSort::Name,
+                        i18n::tr("characters.sort_name"),
+                    );
+                });
+                if followed_character_id.is_some()
+                    && ui.button(i18n::tr("characters.unfollow")).clicked()
+                {
+                    event = Some(CharactersPanelEvent::StopFollow);
+                }
+                ui.separator();
+
+                if characters.is_empty() {
+                    ui.label(i18n::tr("characters.nobody"));
+                }
+
+                egui::ScrollArea::vertical()
+                    .max_height(CHARACTERS_WINDOW_HEIGHT)
+                    .show(ui, |ui| {
+                        for character in &characters {
+                            ui.horizontal(|ui| {
+                                let avatar = self
+                                    .avatars
+                                    .get(&character.public_avatar_uuid())
+                                    .and_then(|texture| texture.as_ref());
+                                let avatar_size =
+                                    egui::Vec2::new(AVATAR_THUMB_SIZE, AVATAR_THUMB_SIZE);
+                                if let Some(texture) = avatar {
+                                    ui.image(texture, avatar_size);
+                                } else {
+                                    ui.allocate_space(avatar_size);
+                                }
+
+                                let name = if character.name.is_empty() {
+                                    i18n::tr("characters.unknown")
+                                } else {
+                                    &character.name
+                                };
+                                let text = format!(
+                                    "{} ({:.0} {})",
+                                    name,
+                                    distance(character),
+                                    i18n::tr("characters.tiles")
+                                );
+                                if ui.selectable_label(false, text).clicked() {
+                                    event = Some(CharactersPanelEvent::CenterCameraOn(
+                                        character.id.clone(),
+                                    ));
+                                }
+                                if ui.button(i18n::tr("characters.card")).clicked() {
+                                    event =
+                                        Some(CharactersPanelEvent::OpenCard(character.id.clone()));
+                                }
+                                let followed =
+                                    followed_character_id.as_ref() == Some(&character.id);
+                                if ui
+                                    .selectable_label(followed, i18n::tr("characters.follow"))
+                                    .clicked()
+                                {
+                                    event = Some(if followed {
+                                        CharactersPanelEvent::StopFollow
+                                    } else {
+                                        CharactersPanelEvent::Follow(character.id.clone())
+                                    });
+                                }
+                            });
+                        }
+                    });
+            });
+
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        self.set_display(display);
+
+        event
+    }
+}
@@ -0,0 +1,123 @@
+use macroquad::prelude::*;
+
+use crate::{
+    entity::{self, build::Build},
+    i18n,
+};
+
+use super::ZoneEngine;
+
+const TOOLTIP_FONT_SIZE: f32 = 18.;
+const TOOLTIP_PADDING: f32 = 6.;
+const TOOLTIP_MOUSE_OFFSET: f32 = 16.;
+const TOOLTIP_BACKGROUND_COLOR: Color = Color::new(0.0, 0.0, 0.0, 0.75);
+pub const CONTINUE_CONSTRUCTION_KEY: KeyCode = KeyCode::Space;
+
+impl ZoneEngine {
+    /// Build under construction located under the mouse, if any
+    pub fn hovered_construction(&self) -> Option<&Build> {
+        let (row_i, col_i) = self.mouse_zone_coordinates;
+        self.state
+            .build_at(row_i as i32, col_i as i32)
+            .filter(|build| build.under_construction)
+    }
+
+    pub fn build_description_url(&self, build_id: i32) -> String {
+        format!("/_describe/build/{}/{}", self.state.player.id, build_id)
+    }
+
+    /// Require hovered construction description to know its name (once per build type)
+    fn require_construction_name(&mut self) {
+        if self.build_name_request.is_some() {
+            return;
+        }
+
+        if let Some(build) = self.hovered_construction() {
+            if !self.build_names.contains_key(&build.build_id) {
+                let build_type = build.build_id.clone();
+                let request = self.client.get_description_request(
+                    self.build_description_url(build.id),
+                    None,
+                    None,
+                );
+                self.build_name_request = Some((build_type, request));
+            }
+        }
+    }
+
+    pub fn proceed_build_name_request(&mut self) {
+        self.require_construction_name();
+
+        if let Some((build_type, request)) = self.build_name_request.as_mut() {
+            if let Some(data) = request.try_recv() {
+                // Fallback on build type to not require it again
+                let mut name = build_type.clone();
+                match data {
+                    Ok(description_string) => {
+                        match entity::description::Description::from_string(&description_string) {
+                            Ok(description) => {
+                                if let Some(title) = description.title {
+                                    name = title;
+                                }
+                            }
+                            Err(error) => {
+                                error!("Error while decoding build description : {}", error);
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        error!("Error while requiring build description : {}", error);
+                    }
+                }
+                self.build_names.insert(build_type.clone(), name);
+                self.build_name_request = None;
+            }
+        }
+    }
+
+    /// Tooltip (near mouse) telling what is being built on hovered tile
+    pub fn draw_construction_tooltip(&self) {
+        if self.current_description.is_some()
+            || self.inventory.is_some()
+            || self.is_mouse_over_window()
+        {
+            return;
+        }
+
+        let build = match self.hovered_construction() {
+            Some(build) => build,
+            None => return,
+        };
+        let name = self
+            .build_names
+            .get(&build.build_id)
+            .map(|name| name.as_str())
+            .unwrap_or("...");
+        let lines = [
+            format!("{} : {}", i18n::tr("construction.in_progress"), name),
+            i18n::tr("construction.continue").to_string(),
+        ];
+
+        let text_width = lines
+            .iter()
+            .map(|line| measure_text(line, None, TOOLTIP_FONT_SIZE as u16, 1.0).width)
+            .fold(0., f32::max);
+        let width = text_width + TOOLTIP_PADDING * 2.;
+        let height = TOOLTIP_FONT_SIZE * lines.len() as f32 + TOOLTIP_PADDING * 2.;
+        let (mouse_x, mouse_y) = mouse_position();
+        // Keep tooltip inside screen
+        let x = (mouse_x + TOOLTIP_MOUSE_OFFSET).min(screen_width() - width);
+        let y = (mouse_y + TOOLTIP_MOUSE_OFFSET).min(screen_height() - height);
+
+        draw_rectangle(x, y, width, height, TOOLTIP_BACKGROUND_COLOR);
+        for (i, line) in lines.iter().enumerate() {
+            draw_text(
+                line,
+                x + TOOLTIP_PADDING,
+                y + TOOLTIP_PADDING + TOOLTIP_FONT_SIZE * (i as f32 + 0.75),
+                TOOLTIP_FONT_SIZE,
+                WHITE,
+            );
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use egui::Context;
+use macroquad::prelude::*;
+
+use quad_net::http_request::Request;
+
+use crate::{client::Client, entity::description::Description, i18n, ui::utils::egui_scale};
+
+use super::{util, ZoneEngine};
+
+const CONTEXT_MENU_WIDTH: f32 = 220.;
+// Touch held this long (seconds) without moving opens the context menu
+const LONG_PRESS_DURATION: f64 = 0.6;
+const LONG_PRESS_MAX_MOVE: f32 = 10.;
+
+/// Something on the tile, with its actions (label, describe url) given by a description
+pub struct ContextMenuEntry {
+    pub label: String,
+    // Description giving entry actions
+    pub description_url: String,
+    // Use description title as label (when label is not known by client)
+    pub label_from_title: bool,
+    pub actions: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+enum LongPress {
+    #[default]
+    Idle,
+    // Begin time and screen position
+    Pending(f64, Vec2),
+    // Touch moved before long press duration
+    Cancelled,
+    Triggered,
+}
+
+/// Menu listing everything on a tile and the actions available for each
+#[derive(Default)]
+pub struct ContextMenu {
+    // row_i, col_i of the tile
+    tile: Option<(i32, i32)>,
+    // Screen position where to place menu at next display
+    place_at: Option<Vec2>,
+    mouse_hover: bool,
+    long_press: LongPress,
+    entries: Vec<ContextMenuEntry>,
+    // Pending descriptions requests, by url
+    requests: Vec<(String, Request)>,
+}
+
+impl ContextMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open menu and require descriptions giving entries actions
+    pub fn open(
+        &mut self,
+        client: &Client,
+        row_i: i32,
+        col_i: i32,
+        screen_position: Vec2,
+        entries: Vec<ContextMenuEntry>,
+    ) {
+        self.tile = Some((row_i, col_i));
+        self.place_at = Some(screen_position);
+        self.requests = vec![];
+        for entry in &entries {
+            if !self
+                .requests
+                .iter()
+                .any(|(url, _)| url == &entry.description_url)
+            {
+                let request =
+                    client.get_description_request(entry.description_url.clone(), None, None);
+                self.requests.push((entry.description_url.clone(), request));
+            }
+        }
+        self.entries = entries;
+    }
+
+    pub fn close(&mut self) {
+        self.tile = None;
+        self.mouse_hover = false;
+        self.requests = vec![];
+    }
+
+    pub fn tile(&self) -> Option<(i32, i32)> {
+        self.tile
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    pub fn proceed_requests(&mut self) {
+        let mut done = vec![];
+
+        for (i, (url, request)) in self.requests.iter_mut().enumerate() {
+            if let Some(data) = request.try_recv() {
+                match data
+                    .map_err(|error| error.to_string())
+                    .and_then(|description_string| Description::from_string(&description_string))
+                {
+                    Ok(description) => {
+                        let links = description.links();
+                        for entry in self
+                            .entries
+                            .iter_mut()
+                            .filter(|entry| &entry.description_url == url)
+                        {
+                            entry.actions = links
+                                .iter()
+                                .filter_map(|part| {
+                                    part.form_action
+                                        .as_ref()
+                                        .map(|action_url| (part.label(), action_url.clone()))
+                                })
+                                .collect();
+                            if entry.label_from_title {
+                                if let Some(title) = &description.title {
+                                    entry.label = title.clone();
+                                }
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        error!("Error while requiring context menu description : {}", error);
+                    }
+                }
+                done.push(i);
+            }
+        }
+
+        for i in done.into_iter().rev() {
+            self.requests.remove(i);
+        }
+    }
+
+    /// Track left button / touch press and return true once when it become a long press
+    pub fn long_pressed(&mut self) -> bool {
+        let now = get_time();
+        let position = Vec2::from(mouse_position());
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            self.long_press = LongPress::Pending(now, position);
+            return false;
+        }
+        if !is_mouse_button_down(MouseButton::Left) {
+            // Keep state during release frame to let click consumers know about it
+            if !is_mouse_button_released(MouseButton::Left) {
+                self.long_press = LongPress::Idle;
+            }
+            return false;
+        }
+
+        if let LongPress::Pending(begin, begin_position) = self.long_press {
+            if position.distance(begin_position) > LONG_PRESS_MAX_MOVE {
+                self.long_press = LongPress::Cancelled;
+            } else if now - begin >= LONG_PRESS_DURATION {
+                self.long_press = LongPress::Triggered;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Current press may become (or is) a long press : player must not move meanwhile
+    pub fn is_long_press_pending(&self) -> bool {
+        matches!(
+            self.long_press,
+            LongPress::Pending(_, _) | LongPress::Triggered
+        )
+    }
+
+    /// Current press is a long press : its release must not be used as a click
+    pub fn is_long_press_triggered(&self) -> bool {
+        matches!(self.long_press, LongPress::Triggered)
+    }
+
+    /// Display menu, return describe url of the clicked action
+    pub fn ui(&mut self, egui_ctx: &Context) -> Option<String> {
+        let (row_i, col_i) = self.tile?;
+        let mut url = None;
+        let mut display = true;
+
+        let mut window = egui::Window::new(format!(
+            "{} ({}, {})",
+            i18n::tr("context.tile"),
+            row_i,
+            col_i
+        ))
+        .id(egui::Id::new("zone_context_menu"))
+        .open(&mut display)
+        .collapsible(false)
+        .resizable(false)
+        .default_width(CONTEXT_MENU_WIDTH);
+        if let Some(place_at) = self.place_at.take() {
+            window = window.current_pos(egui::pos2(
+                place_at.x / egui_scale(),
+                place_at.y / egui_scale(),
+            ));
+        }
+
+        let response = window.show(egui_ctx, |ui| {
+            if self.entries.is_empty() {
+                ui.label(i18n::tr("context.nothing"));
+            }
+
+            for entry in &self.entries {
+                ui.separator();
+                ui.label(&entry.label);
+                let loading = self
+                    .requests
+                    .iter()
+                    .any(|(url, _)| url == &entry.description_url);
+                ui.horizontal_wrapped(|ui| {
+                    if loading {
+                        ui.label("...");
+                    } else if entry.actions.is_empty() {
+                        ui.label(i18n::tr("context.no_action"));
+                    }
+                    for (action_label, action_url) in &entry.actions {
+                        if ui.button(action_label).clicked() {
+                            url = Some(action_url.clone());
+                        }
+                    }
+                });
+            }
+        });
+
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        if !display || url.is_some() || is_key_pressed(KeyCode::Escape) {
+            self.close();
+        }
+
+        url
+    }
+}
+
+impl ZoneEngine {
+    /// Everything located on given tile, with the description giving its actions
+    pub fn context_menu_entries(&self, row_i: i32, col_i: i32) -> Vec<ContextMenuEntry> {
+        let mut entries = vec![];
+        let player = &self.state.player;
+        let main_actions_url = format!("/_describe/character/{}/main_actions", player.id);
+
+        if (player.zone_row_i, player.zone_col_i) == (row_i, col_i) {
+            entries.push(ContextMenuEntry {
+                label: format!("{} ({})", player.name, i18n::tr("context.you")),
+                description_url: main_actions_url,
+                label_from_title: false,
+                actions: vec![],
+            });
+        }
+
+        let tile_entities = match self.state.entities_at(row_i, col_i) {
+            Some(tile_entities) => tile_entities,
+            None => return entries,
+        };
+
+        for character_id in &tile_entities.character_ids {
+            if character_id == &player.id {
+                continue;
+            }
+            if let Some(character) = self.state.characters.get(character_id) {
+                entries.push(ContextMenuEntry {
+                    label: character.name.clone(),
+                    description_url: util::character_card_url(&player.id, &character.id),
+                    label_from_title: character.name.is_empty(),
+                    actions: vec![],
+                });
+            }
+        }
+        if let Some(build) = &tile_entities.build {
+            let build_type = self
+                .state
+                .builds
+                .get(&build.id)
+                .map(|build| build.build_id.as_str())
+                .unwrap_or(&build.tile_id);
+            entries.push(ContextMenuEntry {
+                label: self
+                    .build_names
+                    .get(build_type)
+                    .cloned()
+                    .unwrap_or_else(|| build_type.to_string()),
+                description_url: self.build_description_url(build.id),
+                label_from_title: true,
+                actions: vec![],
+            });
+        }
+        // Ground items names and pick up actions are given by their look description
+        for stuff in &tile_entities.stuffs {
+            entries.push(ContextMenuEntry {
+                label: "...".to_string(),
+                description_url: util::ground_stuff_look_url(&player.id, stuff.id),
+                label_from_title: true,
+                actions: vec![],
+            });
+        }
+        for resource in &tile_entities.resources {
+            entries.push(ContextMenuEntry {
+                label: "...".to_string(),
+                description_url: util::ground_resource_look_url(
+                    &player.id,
+                    &resource.id,
+                    row_i,
+                    col_i,
+                ),
+                label_from_title: true,
+                actions: vec![],
+            });
+        }
+
+        entries
+    }
+}
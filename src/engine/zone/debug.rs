@@ -76,11 +76,30 @@ impl ZoneEngine {
         }
 
         self.draw_tiles_coordinates(draw_area);
+        self.draw_hovered_tile_entities();
+    }
+
+    fn draw_hovered_tile_entities(&self) {
+        let (row_i, col_i) = self.mouse_zone_coordinates;
+        if let Some(tile_entities) = self.state.entities_at(row_i as i32, col_i as i32) {
+            let mut tile_ids = vec![];
+            if let Some(build) = &tile_entities.build {
+                tile_ids.push(build.tile_id.clone());
+            }
+            tile_ids.extend(tile_entities.resources.iter().map(|r| r.tile_id.clone()));
+            tile_ids.extend(tile_entities.stuffs.iter().map(|s| s.tile_id.clone()));
+            tile_ids.extend(tile_entities.character_ids.iter().cloned());
+
+            if !tile_ids.is_empty() {
+                let (mouse_x, mouse_y) = mouse_position();
+                draw_text(&tile_ids.join(", "), mouse_x + 16., mouse_y, 16.0, YELLOW);
+            }
+        }
     }
 
     fn draw_tiles_coordinates(&self, draw_area: ((i32, i32), (i32, i32))) {
         let text_size = 12.0;
-        let zoom_factor = self.zoom_mode.factor();
+        let zoom_factor = self.camera.zoom();
         let text_y_adjust = (self.graphics.tile_height * zoom_factor) - (text_size / 2.0) - 1.0;
         for row_i in 0..self.state.map.tiles.len() {
             for col_i in 0..self.state.map.tiles.first().unwrap().len() {
@@ -3,8 +3,13 @@ use macroquad::prelude::*;
 use crate::{
     action,
     animation::{self, visible::VisibleAnimation},
-    engine::zone::{gui::chat::model::Message, resume::CharacterResume},
-    entity, event, message,
+    engine::zone::{
+        gui::chat::model::{Channel, Message},
+        journal::{JournalEntry, JournalEntryKind},
+        resume::CharacterResume,
+    },
+    entity, event, i18n, message,
+    util::now,
 };
 
 impl super::ZoneEngine {
@@ -34,16 +39,35 @@ impl super::ZoneEngine {
                 zone_row_i,
                 zone_col_i,
             } => {
-                self.state
-                    .builds
-                    .retain(|k, _| k != &(zone_row_i, zone_col_i));
+                let build_id = self
+                    .state
+                    .build_at(zone_row_i, zone_col_i)
+                    .map(|build| build.build_id.clone());
+                self.journal.push(JournalEntry::new(
+                    JournalEntryKind::RemoveBuild,
+                    None,
+                    zone_row_i,
+                    zone_col_i,
+                    build_id,
+                ));
+                self.state.remove_build(zone_row_i, zone_col_i);
             }
             event::ZoneEventType::NewBuild {
                 build,
                 produced_resource_id,
                 produced_stuff_id,
-                producer_character_id: _,
+                producer_character_id,
             } => {
+                let producer_name = producer_character_id
+                    .and_then(|character_id| self.character_name(&character_id));
+                self.journal.push(JournalEntry::new(
+                    JournalEntryKind::NewBuild,
+                    producer_name,
+                    build.row_i,
+                    build.col_i,
+                    Some(build.build_id.clone()),
+                ));
+
                 let mut tile_id: Option<String> = None;
                 if let Some(produced_stuff_id) = produced_stuff_id {
                     tile_id = Some(produced_stuff_id);
@@ -82,9 +106,10 @@ impl super::ZoneEngine {
                     }
                 }
 
-                self.state.builds.insert((build.row_i, build.col_i), build);
-                self.user_logs.push(super::log::UserLog::new(
-                    "Un bâtiment a été construit".to_string(),
+                self.state
+                    .insert_build(&self.graphics.tiles_mapping(), build);
+                self.push_user_log(super::log::UserLog::new(
+                    i18n::tr("log.new_build").to_string(),
                     super::log::UserLogLevel::Info,
                 ));
             }
@@ -94,17 +119,33 @@ impl super::ZoneEngine {
                 character_id,
                 spritesheet_filename,
             } => {
-                self.state.characters.insert(
-                    character_id.clone(),
-                    entity::character::Character::minimal(
+                self.state
+                    .insert_character(entity::character::Character::minimal(
                         character_id.clone(),
                         zone_row_i,
                         zone_col_i,
                         spritesheet_filename.clone(),
-                    ),
+                    ));
+                let character_name = self.character_name(&character_id);
+                // Name of a never seen character is given by zone characters list
+                if character_name.is_none() && self.zone_characters_request.is_none() {
+                    self.zone_characters_request = Some(self.client.get_characters_request(
+                        self.state.player.world_row_i,
+                        self.state.player.world_col_i,
+                    ));
+                }
+                self.journal.push(
+                    JournalEntry::new(
+                        JournalEntryKind::CharacterEnter,
+                        character_name,
+                        zone_row_i,
+                        zone_col_i,
+                        None,
+                    )
+                    .with_character_id(&character_id),
                 );
-                self.user_logs.push(super::log::UserLog::new(
-                    "Un personnage vient d'arriver".to_string(),
+                self.push_user_log(super::log::UserLog::new(
+                    i18n::tr("log.character_enter").to_string(),
                     super::log::UserLogLevel::Info,
                 ));
                 if let Some(spritesheet_filename) = spritesheet_filename {
@@ -130,17 +171,23 @@ impl super::ZoneEngine {
                 }
             }
             event::ZoneEventType::CharacterExit { character_id } => {
-                self.state.characters.remove(&character_id);
+                if let Some(character) = self.state.characters.get(&character_id) {
+                    self.journal.push(JournalEntry::new(
+                        JournalEntryKind::CharacterExit,
+                        self.character_name(&character_id),
+                        character.zone_row_i,
+                        character.zone_col_i,
+                        None,
+                    ));
+                }
+                self.state.remove_character(&character_id);
             }
             event::ZoneEventType::PlayerMove {
                 to_row_i,
                 to_col_i,
                 character_id,
             } => {
-                if let Some(character) = self.state.characters.get_mut(&character_id) {
-                    character.zone_row_i = to_row_i;
-                    character.zone_col_i = to_col_i;
-                }
+                self.state.move_character(&character_id, to_row_i, to_col_i);
             }
             event::ZoneEventType::ZoneTileReplace {
                 row_i,
@@ -148,33 +195,62 @@ impl super::ZoneEngine {
                 new_tile_id,
             } => {
                 self.state.map.replace_tile(row_i, col_i, new_tile_id);
+                if self.settings.terrain_transitions {
+                    self.state.autotiles.update_around(
+                        &self.state.map,
+                        &self.graphics.tiles_mapping(),
+                        row_i as i32,
+                        col_i as i32,
+                    );
+                }
             }
             event::ZoneEventType::ZoneGroundResourceRemoved {
                 row_i,
                 col_i,
                 resource_id,
             } => {
-                if let Some(resources) = self.state.resources.get_mut(&(row_i, col_i)) {
-                    resources.retain(|r| !(r.id == resource_id))
-                }
+                self.journal.push(JournalEntry::new(
+                    JournalEntryKind::GroundResourceRemoved,
+                    None,
+                    row_i,
+                    col_i,
+                    Some(resource_id.clone()),
+                ));
+                self.state.remove_resource(row_i, col_i, &resource_id);
             }
             event::ZoneEventType::ZoneGroundStuffRemoved { stuff_id } => {
-                self.state.stuffs.remove(&stuff_id);
+                if let Some(stuff) = self.state.stuffs.get(&stuff_id) {
+                    self.journal.push(JournalEntry::new(
+                        JournalEntryKind::GroundStuffRemoved,
+                        None,
+                        stuff.zone_row_i,
+                        stuff.zone_col_i,
+                        Some(stuff.stuff_id.clone()),
+                    ));
+                }
+                self.state.remove_stuff(stuff_id);
             }
             event::ZoneEventType::ZoneGroundResourceAdded {
                 row_i,
                 col_i,
                 resource_id,
-            } => self
-                .state
-                .resources
-                .entry((row_i, col_i))
-                .or_insert(vec![])
-                .push(entity::resource::Resource {
-                    id: resource_id,
-                    zone_row_i: row_i,
-                    zone_col_i: col_i,
-                }),
+            } => {
+                self.journal.push(JournalEntry::new(
+                    JournalEntryKind::GroundResourceAdded,
+                    None,
+                    row_i,
+                    col_i,
+                    Some(resource_id.clone()),
+                ));
+                self.state.add_resource(
+                    &self.graphics.tiles_mapping(),
+                    entity::resource::Resource {
+                        id: resource_id,
+                        zone_row_i: row_i,
+                        zone_col_i: col_i,
+                    },
+                )
+            }
             event::ZoneEventType::ZoneGroundStuffAdded {
                 id_,
                 stuff_id,
@@ -182,8 +258,15 @@ impl super::ZoneEngine {
                 zone_col_i,
                 classes,
             } => {
-                self.state.stuffs.insert(
-                    id_,
+                self.journal.push(JournalEntry::new(
+                    JournalEntryKind::GroundStuffAdded,
+                    None,
+                    zone_row_i,
+                    zone_col_i,
+                    Some(stuff_id.clone()),
+                ));
+                self.state.insert_stuff(
+                    &self.graphics.tiles_mapping(),
                     entity::stuff::Stuff {
                         id: id_,
                         stuff_id,
@@ -200,9 +283,44 @@ impl super::ZoneEngine {
                         if let Some(before) = &self.resume {
                             self.blinking_icons
                                 .extend(before.icons_from_compare(&resume_));
+
+                            // Display changes (action points spent, health) over player
+                            let player = &self.state.player;
+                            let dest = self.zone_position_to_screen_position(
+                                player.zone_row_i as f32 - 1.,
+                                player.zone_col_i as f32 + 0.5,
+                            );
+                            for (i, (text, color)) in before
+                                .changes_from_compare(&resume_)
+                                .into_iter()
+                                .enumerate()
+                            {
+                                let offset = Vec2::new(
+                                    0.,
+                                    -(i as f32) * animation::text::FLOATING_TEXT_FONT_SIZE,
+                                );
+                                self.ui_animations.push(Box::new(
+                                    animation::text::FloatingTextAnimation::new(
+                                        text,
+                                        dest + offset,
+                                        color,
+                                    ),
+                                ));
+                            }
+                        }
+                        self.stats_history.push(&resume_);
+                        for alert in self.need_alerts.check(&resume_) {
+                            self.push_user_log(super::log::UserLog::error(alert));
                         }
 
+                        // Character state changed, its transport mode may have changed too (no
+                        // event carry it)
+                        let changed = matches!(&self.resume, Some(before) if before != &resume_);
                         self.resume = Some(resume_);
+                        if changed && self.player_character_request.is_none() {
+                            self.player_character_request =
+                                Some(self.client.get_character_request(&self.state.player.id));
+                        }
                     }
                     Err(error) => {
                         error!("{}", error);
@@ -214,21 +332,23 @@ impl super::ZoneEngine {
                     event::TopBarMessageType::NORMAL => super::log::UserLogLevel::Info,
                     event::TopBarMessageType::ERROR => super::log::UserLogLevel::Error,
                 };
-                self.user_logs
-                    .push(super::log::UserLog::new(message, message_level));
+                self.push_user_log(super::log::UserLog::new(message, message_level));
             }
             event::ZoneEventType::NewChatMessage {
                 character_id,
                 message,
                 system,
                 silent,
+                private,
+                to_character_id,
             } => {
                 if let Some(character_id) = character_id {
+                    let muted = self.chat_state.is_muted(&character_id);
                     if let Some(character) = self.state.characters.get(&character_id) {
-                        self.chat_state
-                            .add_message(Message::character(message), silent);
-
-                        if !silent {
+                        let character_position = (character.zone_row_i, character.zone_col_i);
+                        if !silent && !muted && self.settings.speech_bubbles {
+                            self.add_speech_bubble(&character_id, &message);
+                        } else if !silent && !muted {
                             let source = Rect {
                                 x: 352.,
                                 y: 192.,
@@ -236,19 +356,46 @@ impl super::ZoneEngine {
                                 h: 32.,
                             };
                             let dest = self.zone_position_to_screen_position(
-                                (character.zone_row_i - 1) as f32 - 0.5,
-                                character.zone_col_i as f32 + 0.5,
+                                (character_position.0 - 1) as f32 - 0.5,
+                                character_position.1 as f32 + 0.5,
                             );
-                            self.ui_animations.push(Box::new(VisibleAnimation::new(
-                                source,
-                                dest,
-                                self.frame_i + 60 * 5,
-                            )));
+                            self.ui_animations
+                                .push(Box::new(VisibleAnimation::new(source, dest, 5.0)));
                         }
                     }
+
+                    // Unknown sender name is set when zone characters are received
+                    let sender_name = self.character_name(&character_id);
+                    if sender_name.is_none() && self.zone_characters_request.is_none() {
+                        self.zone_characters_request = Some(self.client.get_characters_request(
+                            self.state.player.world_row_i,
+                            self.state.player.world_col_i,
+                        ));
+                    }
+                    // Private conversation is keyed by the other character
+                    let channel = if private {
+                        match to_character_id {
+                            Some(to_character_id) if character_id == self.state.player.id => {
+                                Channel::Private(to_character_id)
+                            }
+                            _ => Channel::Private(character_id.clone()),
+                        }
+                    } else {
+                        Channel::Zone
+                    };
+                    self.chat_state.add_message(
+                        Message::character(
+                            character_id,
+                            sender_name.unwrap_or_default(),
+                            message,
+                            channel,
+                            now(),
+                        ),
+                        silent,
+                    );
                 } else if system {
                     self.chat_state
-                        .add_message(Message::system(message), silent);
+                        .add_message(Message::system(message, now()), silent);
                 }
             }
             _ => {}
@@ -257,6 +404,19 @@ impl super::ZoneEngine {
         messages
     }
 
+    /// Name of given character if known (characters entering zone have no name)
+    pub fn character_name(&self, character_id: &str) -> Option<String> {
+        if self.state.player.id == character_id {
+            return Some(self.state.player.name.clone());
+        }
+
+        self.state
+            .characters
+            .get(character_id)
+            .map(|character| character.name.clone())
+            .filter(|name| !name.is_empty())
+    }
+
     pub fn update_current_action_according_new_quick_actions(&mut self) {
         if let Some(action) = &self.current_action {
             if let Some(quick_action) = self
@@ -16,6 +16,9 @@ const CHAT_BUTTON_Y: f32 = 768.;
 const CHAT_BUTTON_WIDTH: f32 = 64.;
 const CHAT_BUTTON_HEIGHT: f32 = 64.;
 
+// Where next element can be drawn under buttons column
+pub const BUTTONS_COLUMN_HEIGHT: f32 = ZOOM_BUTTON_HEIGHT + RUN_BUTTON_HEIGHT + CHAT_BUTTON_HEIGHT;
+
 pub fn draw_zoom_button(graphics: &graphics::Graphics, active: bool, right_offset: f32) -> bool {
     let draw_start_x = screen_width() - ZOOM_BUTTON_WIDTH as f32 - right_offset;
     let draw_end_x = draw_start_x + ZOOM_BUTTON_WIDTH;
@@ -29,11 +32,9 @@ pub fn draw_zoom_button(graphics: &graphics::Graphics, active: bool, right_offse
     };
     let source_y = ZOOM_BUTTON_Y;
 
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         draw_start_x,
         draw_start_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 source_x,
@@ -65,11 +66,9 @@ pub fn draw_run_button(graphics: &graphics::Graphics, active: bool, right_offset
     };
     let source_y = RUN_BUTTON_Y;
 
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         draw_start_x,
         draw_start_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 source_x,
@@ -101,11 +100,9 @@ pub fn draw_chat_button(graphics: &graphics::Graphics, active: bool, right_offse
     };
     let source_y = CHAT_BUTTON_Y;
 
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         draw_start_x,
         draw_start_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 source_x,
@@ -0,0 +1,245 @@
+use crate::i18n;
+
+use super::model::Channel;
+
+/// What a chat input asks to do
+pub enum ChatCommandEffect {
+    // Message to send to current channel (zone, or character of private conversation)
+    Send(String),
+    // Message to send to zone, whatever current channel
+    SendZone(String),
+    // Recipient character id and name, message
+    SendPrivate(String, String, String),
+    ClearChannel,
+    SwitchChannel(Channel),
+    // Text displayed only to player
+    Info(String),
+}
+
+/// Player informations and known characters, to resolve names given to commands
+pub struct ChatCommandContext<'a> {
+    pub player_name: &'a str,
+    // Character id and name
+    pub characters: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> ChatCommandContext<'a> {
+    /// Character which name begin the text, and the remaining text
+    fn find_character<'t>(&self, text: &'t str) -> Option<(&'a str, &'a str, &'t str)> {
+        self.characters
+            .iter()
+            .filter(|(_, name)| {
+                !name.is_empty()
+                    && starts_with_ignore_case(text, name)
+                    && text[name.len()..]
+                        .chars()
+                        .next()
+                        .map(char::is_whitespace)
+                        .unwrap_or(true)
+            })
+            // Longest name first, for names beginning by another one
+            .max_by_key(|(_, name)| name.len())
+            .map(|(id, name)| (*id, *name, text[name.len()..].trim_start()))
+    }
+}
+
+pub struct ChatCommand {
+    pub name: &'static str,
+    pub usage: &'static str,
+    // Translation key
+    pub description: &'static str,
+    run: fn(&str, &ChatCommandContext) -> ChatCommandEffect,
+}
+
+/// Available chat commands. Add new commands here
+pub const CHAT_COMMANDS: [ChatCommand; 5] = [
+    ChatCommand {
+        name: "me",
+        usage: "/me <action>",
+        description: "chat.command.me",
+        run: |arguments, context| {
+            if arguments.is_empty() {
+                return ChatCommandEffect::Info(format!(
+                    "{} : /me <action>",
+                    i18n::tr("chat.usage")
+                ));
+            }
+            ChatCommandEffect::Send(format!("* {} {}", context.player_name, arguments))
+        },
+    },
+    ChatCommand {
+        name: "w",
+        usage: "/w <personnage> <message>",
+        description: "chat.command.w",
+        run: |arguments, context| match context.find_character(arguments) {
+            Some((_, _, "")) | None => ChatCommandEffect::Info(format!(
+                "{} : /w <personnage> <message>",
+                i18n::tr("chat.usage")
+            )),
+            Some((character_id, character_name, message)) => ChatCommandEffect::SendPrivate(
+                character_id.to_string(),
+                character_name.to_string(),
+                message.to_string(),
+            ),
+        },
+    },
+    ChatCommand {
+        name: "clear",
+        usage: "/clear",
+        description: "chat.command.clear",
+        run: |_, _| ChatCommandEffect::ClearChannel,
+    },
+    ChatCommand {
+        name: "zone",
+        usage: "/zone [message]",
+        description: "chat.command.zone",
+        run: |arguments, _| {
+            if arguments.is_empty() {
+                ChatCommandEffect::SwitchChannel(Channel::Zone)
+            } else {
+                ChatCommandEffect::SendZone(arguments.to_string())
+            }
+        },
+    },
+    ChatCommand {
+        name: "help",
+        usage: "/help",
+        description: "chat.command.help",
+        run: |_, _| {
+            ChatCommandEffect::Info(
+                CHAT_COMMANDS
+                    .iter()
+                    .map(|command| format!("{} : {}", command.usage, i18n::tr(command.description)))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )
+        },
+    },
+];
+
+/// Interpret chat input : a command when it begin with "/", a zone message otherwise
+pub fn interpret(input: &str, context: &ChatCommandContext) -> ChatCommandEffect {
+    let input = input.trim();
+    let command_line = match input.strip_prefix('/') {
+        Some(command_line) => command_line,
+        None => return ChatCommandEffect::Send(input.to_string()),
+    };
+
+    let (name, arguments) = match command_line.split_once(' ') {
+        Some((name, arguments)) => (name, arguments.trim()),
+        None => (command_line, ""),
+    };
+    match CHAT_COMMANDS.iter().find(|command| command.name == name) {
+        Some(command) => (command.run)(arguments, context),
+        None => ChatCommandEffect::Info(format!(
+            "{} : /{} ({})",
+            i18n::tr("chat.unknown_command"),
+            name,
+            i18n::tr("chat.see_help")
+        )),
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .map(|start| start.to_lowercase() == prefix.to_lowercase())
+        .unwrap_or(false)
+}
+
+/// Possible completions of chat input (commands names, then characters names for /w)
+pub fn completions(input: &str, context: &ChatCommandContext) -> Vec<String> {
+    let command_line = match input.strip_prefix('/') {
+        Some(command_line) => command_line,
+        None => return vec![],
+    };
+
+    match command_line.split_once(' ') {
+        None => CHAT_COMMANDS
+            .iter()
+            .filter(|command| command.name.starts_with(command_line))
+            .map(|command| format!("/{} ", command.name))
+            .collect(),
+        Some(("w", partial_name)) if !partial_name.contains(' ') => context
+            .characters
+            .iter()
+            .filter(|(_, name)| !name.is_empty() && starts_with_ignore_case(name, partial_name))
+            .map(|(_, name)| format!("/w {} ", name))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> ChatCommandContext<'static> {
+        ChatCommandContext {
+            player_name: "Bob",
+            characters: vec![
+                ("1", "Alice"),
+                ("2", "Alice Martin"),
+                ("3", "Paul"),
+                ("4", ""),
+            ],
+        }
+    }
+
+    #[test]
+    fn whisper_sends_private_message_to_named_character() {
+        match interpret("/w paul bonjour toi", &context()) {
+            ChatCommandEffect::SendPrivate(id, name, message) => {
+                assert_eq!(id, "3");
+                assert_eq!(name, "Paul");
+                assert_eq!(message, "bonjour toi");
+            }
+            _ => panic!("Private message expected"),
+        }
+    }
+
+    #[test]
+    fn whisper_prefers_longest_matching_name() {
+        match interpret("/w Alice Martin salut", &context()) {
+            ChatCommandEffect::SendPrivate(id, _, message) => {
+                assert_eq!(id, "2");
+                assert_eq!(message, "salut");
+            }
+            _ => panic!("Private message expected"),
+        }
+    }
+
+    #[test]
+    fn whisper_without_message_or_unknown_character_gives_usage() {
+        assert!(matches!(
+            interpret("/w Paul", &context()),
+            ChatCommandEffect::Info(_)
+        ));
+        assert!(matches!(
+            interpret("/w Pierre bonjour", &context()),
+            ChatCommandEffect::Info(_)
+        ));
+        // Name must be followed by a space
+        assert!(matches!(
+            interpret("/w Paulo bonjour", &context()),
+            ChatCommandEffect::Info(_)
+        ));
+    }
+
+    #[test]
+    fn completes_commands_names() {
+        assert_eq!(completions("/c", &context()), vec!["/clear "]);
+        assert_eq!(completions("/w", &context()), vec!["/w "]);
+        assert!(completions("bonjour", &context()).is_empty());
+    }
+
+    #[test]
+    fn completes_characters_names_after_whisper() {
+        assert_eq!(
+            completions("/w al", &context()),
+            vec!["/w Alice ", "/w Alice Martin "]
+        );
+        assert_eq!(completions("/w ", &context()).len(), 3);
+        assert!(completions("/w Paul bonjour", &context()).is_empty());
+        assert!(completions("/me al", &context()).is_empty());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::ui::utils::{egui_scale, is_mobile};
+use crate::{
+    i18n,
+    ui::utils::{egui_scale, is_mobile},
+};
 use egui::{Context, Pos2, Ui};
 use macroquad::prelude::*;
 
@@ -70,11 +73,13 @@ impl Display {
 
 pub struct ChatDisplayer<'s> {
     state: &'s State,
+    // Possible completions of current input (see `command::completions`)
+    completions: Vec<String>,
 }
 
 impl<'s> ChatDisplayer<'s> {
-    pub fn new(state: &'s State) -> Self {
-        Self { state }
+    pub fn new(state: &'s State, completions: Vec<String>) -> Self {
+        Self { state, completions }
     }
 
     pub fn ui(&mut self, egui_ctx: &Context) -> DisplayState {
@@ -113,8 +118,28 @@ impl<'s> ChatDisplayer<'s> {
         let display = Display::from_env();
 
         ui.horizontal(|ui| {
-            ui.add(egui::Button::new("Button1"));
-            ui.add(egui::Button::new("Button2"));
+            for channel in self.state.channels() {
+                let unread_count = self.state.unread_count(&channel);
+                let name = self.state.channel_name(&channel);
+                let text = if unread_count > 0 {
+                    format!("{} ({})", name, unread_count)
+                } else {
+                    name
+                };
+                if ui
+                    .selectable_label(self.state.current_channel() == &channel, text)
+                    .clicked()
+                {
+                    display_state.selected_channel = Some(channel);
+                }
+            }
+
+            ui.label("🔍");
+            let search_input = ui.add(
+                egui::TextEdit::singleline(&mut display_state.search_value)
+                    .hint_text(i18n::tr("chat.search_hint")),
+            );
+            display_state.search_focused = search_input.has_focus();
         });
 
         ui.separator();
@@ -124,24 +149,85 @@ impl<'s> ChatDisplayer<'s> {
             .max_height(max_height)
             .show(ui, |ui| {
                 for message in self.state.messages() {
-                    ui.label(message.message());
+                    match (message.sender_id(), message.sender_name()) {
+                        (Some(sender_id), Some(sender_name)) => {
+                            // Name not yet received from server
+                            let sender_name = if sender_name.is_empty() {
+                                "..."
+                            } else {
+                                sender_name
+                            };
+                            ui.label(format!(
+                                "[{}] {} : {}",
+                                message.time(),
+                                sender_name,
+                                message.message()
+                            ))
+                            .context_menu(|ui| {
+                                if ui
+                                    .button(format!("{} {}", i18n::tr("chat.hide"), sender_name))
+                                    .clicked()
+                                {
+                                    display_state.mute =
+                                        Some((sender_id.to_string(), sender_name.to_string()));
+                                    ui.close_menu();
+                                }
+                            });
+                        }
+                        _ => {
+                            ui.label(format!("[{}] {}", message.time(), message.message()));
+                        }
+                    }
                 }
                 if self.state.just_opened() {
                     ui.scroll_to_cursor(Some(egui::Align::BOTTOM));
                 }
             });
 
+        if !self.state.muted().is_empty() {
+            ui.collapsing(i18n::tr("chat.hidden_senders"), |ui| {
+                for (character_id, character_name) in self.state.muted() {
+                    ui.horizontal(|ui| {
+                        ui.label(character_name);
+                        if ui.button(i18n::tr("chat.show_again")).clicked() {
+                            display_state.unmute = Some(character_id.clone());
+                        }
+                    });
+                }
+            });
+        }
+
         ui.separator();
 
+        if !self.completions.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                for completion in &self.completions {
+                    if ui.small_button(completion.trim_end()).clicked() {
+                        display_state.completion = Some(completion.clone());
+                    }
+                }
+            });
+        }
+
         ui.with_layout(egui::Layout::left_to_right(egui::Align::Min), |ui| {
             if is_mobile() {
                 display_state.input_validated = ui.add(egui::Button::new("📝")).clicked();
             }
 
-            let chat_input = ui.add(egui::TextEdit::singleline(&mut display_state.input_value));
+            // Lock focus to keep tab key for completion
+            let chat_input =
+                ui.add(egui::TextEdit::singleline(&mut display_state.input_value).lock_focus(true));
             display_state.input_focused = chat_input.has_focus();
             display_state.input_gained_focus = chat_input.gained_focus();
 
+            if display_state.input_focused {
+                if is_key_pressed(KeyCode::Tab) {
+                    display_state.completion = self.completions.first().cloned();
+                }
+                display_state.recall_previous = is_key_pressed(KeyCode::Up);
+                display_state.recall_next = is_key_pressed(KeyCode::Down);
+            }
+
             if self.state.request_focus() {
                 chat_input.request_focus()
             }
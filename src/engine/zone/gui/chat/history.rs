@@ -0,0 +1,24 @@
+use crate::storage;
+
+use super::model::Message;
+
+// Older messages are forgotten beyond this count
+pub const CHAT_HISTORY_MAX_MESSAGES: usize = 500;
+
+fn storage_key(character_id: &str) -> String {
+    format!("__CHAT_HISTORY__{}", character_id)
+}
+
+/// Chat messages previously received by given character (local storage on wasm, file on native)
+pub fn load(character_id: &str) -> Vec<Message> {
+    storage::load_json(&storage_key(character_id)).unwrap_or_default()
+}
+
+pub fn save(character_id: &str, messages: &[Message]) {
+    // Texts displayed only to player are not kept
+    let messages: Vec<&Message> = messages
+        .iter()
+        .filter(|message| !message.is_local())
+        .collect();
+    storage::save_json(&storage_key(character_id), &messages)
+}
@@ -1,3 +1,5 @@
+pub mod command;
 pub mod display;
+pub mod history;
 pub mod model;
 pub mod state;
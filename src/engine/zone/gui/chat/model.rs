@@ -1,23 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{i18n, util::format_time};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Zone,
+    System,
+    // Private conversation with given character id
+    Private(String),
+}
+
+impl Channel {
+    /// Channels always displayed (private ones are added with conversations)
+    pub const FIXED: [Channel; 2] = [Channel::Zone, Channel::System];
+
+    /// Name of fixed channels (private ones are named by their character)
+    pub fn name(&self) -> &str {
+        match self {
+            Channel::Zone => i18n::tr("chat.channel.zone"),
+            Channel::System => i18n::tr("chat.channel.system"),
+            Channel::Private(_) => i18n::tr("chat.channel.private"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Message {
     message: String,
-    system: bool,
+    // Sender character id and name (none for system messages)
+    sender: Option<(String, String)>,
+    // Reception timestamp (seconds since epoch)
+    timestamp: f64,
+    channel: Channel,
+    // Text displayed only to player (never saved in history)
+    #[serde(skip)]
+    local: bool,
 }
 
 impl Message {
-    pub fn character(message: String) -> Self {
+    pub fn character(
+        sender_id: String,
+        sender_name: String,
+        message: String,
+        channel: Channel,
+        timestamp: f64,
+    ) -> Self {
         Self {
             message,
-            system: false,
+            sender: Some((sender_id, sender_name)),
+            timestamp,
+            channel,
+            local: false,
         }
     }
-    pub fn system(message: String) -> Self {
+
+    pub fn system(message: String, timestamp: f64) -> Self {
+        Self {
+            message,
+            sender: None,
+            timestamp,
+            channel: Channel::System,
+            local: false,
+        }
+    }
+
+    /// Text only displayed to player (commands results)
+    pub fn info(message: String, channel: Channel, timestamp: f64) -> Self {
         Self {
             message,
-            system: true,
+            sender: None,
+            timestamp,
+            channel,
+            local: true,
         }
     }
 
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    pub fn sender_id(&self) -> Option<&str> {
+        self.sender.as_ref().map(|(id, _)| id.as_str())
+    }
+
+    pub fn sender_name(&self) -> Option<&str> {
+        self.sender.as_ref().map(|(_, name)| name.as_str())
+    }
+
+    /// Set sender name when it was unknown at reception. Return true if it was
+    pub fn resolve_sender_name(&mut self, character_id: &str, name: &str) -> bool {
+        if let Some((sender_id, sender_name)) = self.sender.as_mut() {
+            if sender_name.is_empty() && sender_id == character_id {
+                *sender_name = name.to_string();
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.local
+    }
+
+    /// Same message received again (server send last messages when entering zone)
+    pub fn is_same(&self, other: &Message) -> bool {
+        self.message == other.message
+            && self.sender_id() == other.sender_id()
+            && self.channel == other.channel
+    }
+
+    /// Message text or sender name contains given text (case insensitive)
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+        self.message.to_lowercase().contains(&search)
+            || self
+                .sender_name()
+                .map(|name| name.to_lowercase().contains(&search))
+                .unwrap_or(false)
+    }
+
+    pub fn time(&self) -> String {
+        format_time(self.timestamp)
+    }
 }
@@ -1,38 +1,87 @@
-use crate::ui::utils::is_mobile;
+use ahash::AHashMap;
 
-use super::model::Message;
+use crate::{ui::utils::is_mobile, util::now};
+
+use super::{
+    history,
+    model::{Channel, Message},
+};
+
+// Sent inputs remembered for up/down recall
+const SENT_INPUTS_MAX: usize = 50;
+// Duration (seconds) after chat request during which server replays last messages
+const CHAT_REPLAY_DURATION: f64 = 5.;
+// Minimum duration (seconds) between two history saves
+const CHAT_HISTORY_SAVE_INTERVAL: f64 = 5.;
 
 pub struct State {
+    // Character owning the chat history
+    character_id: String,
     messages: Vec<Message>,
-    unread: bool,
+    // Unread messages count, by channel
+    unread: AHashMap<Channel, usize>,
+    // Private conversations character id and name (empty until known), in opening order
+    conversations: Vec<(String, String)>,
+    current_channel: Channel,
+    // Muted characters ids and names
+    muted: Vec<(String, String)>,
     display: bool,
     just_opened: bool,
     input_focused: bool,
     request_focus: bool,
     surrender_focus: bool,
     input_value: String,
+    // Inputs previously sent, and position in them when recalling with up/down keys
+    sent_inputs: Vec<String>,
+    sent_inputs_cursor: Option<usize>,
+    search_focused: bool,
+    // Text to search in messages
+    search_value: String,
     mouse_hover: bool,
+    // Server replays last messages until this timestamp (seconds since epoch)
+    replay_until: f64,
+    // History changed since last save, and last save timestamp
+    history_changed: bool,
+    history_saved_at: f64,
 }
 
 impl State {
-    pub fn new() -> Self {
-        Self {
+    pub fn new(character_id: &str) -> Self {
+        let messages = history::load(character_id);
+        let mut state = Self {
+            character_id: character_id.to_string(),
             messages: vec![],
-            unread: false,
+            unread: AHashMap::new(),
+            conversations: vec![],
+            current_channel: Channel::Zone,
+            muted: vec![],
             display: false,
             just_opened: false,
             input_focused: false,
             request_focus: false,
             surrender_focus: false,
             input_value: "".to_string(),
+            sent_inputs: vec![],
+            sent_inputs_cursor: None,
+            search_focused: false,
+            search_value: "".to_string(),
             mouse_hover: false,
+            replay_until: 0.,
+            history_changed: false,
+            history_saved_at: now(),
+        };
+        // Restore private conversations tabs from history
+        for message in &messages {
+            state.open_conversation_of(message);
         }
+        state.messages = messages;
+        state
     }
 
     pub fn set_display(&mut self, display: bool) {
         self.display = display;
         if self.display {
-            self.unread = false;
+            self.unread.remove(&self.current_channel);
             self.request_focus = !is_mobile();
         } else {
             self.input_focused = false;
@@ -43,8 +92,9 @@ impl State {
         self.display
     }
 
+    /// Chat input or search input is focused
     pub fn is_input_focused(&self) -> bool {
-        self.input_focused
+        self.input_focused || self.search_focused
     }
 
     pub fn is_mouse_hover(&self) -> bool {
@@ -52,18 +102,208 @@ impl State {
     }
 
     pub fn have_unread(&self) -> bool {
-        self.unread
+        self.unread.values().any(|count| *count > 0)
+    }
+
+    pub fn unread_count(&self, channel: &Channel) -> usize {
+        self.unread.get(channel).copied().unwrap_or(0)
+    }
+
+    /// Fixed channels, then private conversations ones
+    pub fn channels(&self) -> Vec<Channel> {
+        Channel::FIXED
+            .into_iter()
+            .chain(
+                self.conversations
+                    .iter()
+                    .map(|(character_id, _)| Channel::Private(character_id.clone())),
+            )
+            .collect()
+    }
+
+    /// Channel tab name : other character name for private conversations
+    pub fn channel_name(&self, channel: &Channel) -> String {
+        match channel {
+            Channel::Private(character_id) => self
+                .conversations
+                .iter()
+                .find(|(id, name)| id == character_id && !name.is_empty())
+                .map(|(_, name)| name.clone())
+                .unwrap_or_else(|| "...".to_string()),
+            _ => channel.name().to_string(),
+        }
+    }
+
+    /// Add private conversation with given character (if not already), or set its name if unknown
+    pub fn open_conversation(&mut self, character_id: &str, character_name: &str) {
+        match self
+            .conversations
+            .iter_mut()
+            .find(|(id, _)| id == character_id)
+        {
+            Some((_, name)) => {
+                if name.is_empty() {
+                    *name = character_name.to_string();
+                }
+            }
+            None => self
+                .conversations
+                .push((character_id.to_string(), character_name.to_string())),
+        }
+    }
+
+    fn open_conversation_of(&mut self, message: &Message) {
+        if let Channel::Private(character_id) = message.channel() {
+            // Sender is the other character, or player (who don't give other character name)
+            let character_name = match (message.sender_id(), message.sender_name()) {
+                (Some(sender_id), Some(sender_name)) if sender_id == character_id => sender_name,
+                _ => "",
+            };
+            self.open_conversation(character_id, character_name);
+        }
+    }
+
+    /// Server is about to replay last zone messages (which can already be in restored history)
+    pub fn expect_replay(&mut self) {
+        self.replay_until = now() + CHAT_REPLAY_DURATION;
     }
 
     pub fn add_message(&mut self, message: Message, silent: bool) {
+        let muted = message
+            .sender_id()
+            .map(|sender_id| self.is_muted(sender_id))
+            .unwrap_or(false);
+        let replayed = silent && !message.is_local() && now() < self.replay_until;
+        if replayed && self.messages.iter().any(|known| known.is_same(&message)) {
+            return;
+        }
+        if !silent && !muted && (!self.display || &self.current_channel != message.channel()) {
+            *self.unread.entry(message.channel().clone()).or_insert(0) += 1;
+        }
+        self.open_conversation_of(&message);
+
+        if !message.is_local() {
+            self.history_changed = true;
+        }
         self.messages.push(message);
-        if !silent {
-            self.unread = !self.display;
+        if self.messages.len() > history::CHAT_HISTORY_MAX_MESSAGES {
+            let overflow = self.messages.len() - history::CHAT_HISTORY_MAX_MESSAGES;
+            self.messages.drain(..overflow);
         }
     }
 
-    pub fn messages(&self) -> &Vec<Message> {
-        &self.messages
+    /// Save history if it changed, at most once per interval (or immediately when forced)
+    pub fn save_history(&mut self, force: bool) {
+        if !self.history_changed
+            || (!force && now() - self.history_saved_at < CHAT_HISTORY_SAVE_INTERVAL)
+        {
+            return;
+        }
+
+        history::save(&self.character_id, &self.messages);
+        self.history_changed = false;
+        self.history_saved_at = now();
+    }
+
+    /// Set sender name of messages received when it was unknown
+    pub fn resolve_sender_name(&mut self, character_id: &str, name: &str) {
+        let mut resolved = false;
+        for message in self.messages.iter_mut() {
+            resolved |= message.resolve_sender_name(character_id, name);
+        }
+        if resolved {
+            self.history_changed = true;
+        }
+        if self.conversations.iter().any(|(id, _)| id == character_id) {
+            self.open_conversation(character_id, name);
+        }
+    }
+
+    /// Messages of current channel matching search, without muted characters ones
+    pub fn messages(&self) -> impl Iterator<Item = &Message> {
+        self.messages.iter().filter(|message| {
+            message.channel() == &self.current_channel
+                && (self.search_value.is_empty() || message.matches(&self.search_value))
+                && !message
+                    .sender_id()
+                    .map(|sender_id| self.is_muted(sender_id))
+                    .unwrap_or(false)
+        })
+    }
+
+    /// Display a text to player in current channel
+    pub fn add_info(&mut self, text: String) {
+        self.add_message(
+            Message::info(text, self.current_channel.clone(), now()),
+            true,
+        );
+    }
+
+    pub fn clear_current_channel(&mut self) {
+        let channel = &self.current_channel;
+        self.messages.retain(|message| message.channel() != channel);
+        self.history_changed = true;
+    }
+
+    pub fn push_sent_input(&mut self, input: String) {
+        self.sent_inputs.push(input);
+        if self.sent_inputs.len() > SENT_INPUTS_MAX {
+            self.sent_inputs.remove(0);
+        }
+        self.sent_inputs_cursor = None;
+    }
+
+    /// Put previous (or next) sent input in chat input
+    fn recall_sent_input(&mut self, previous: bool) {
+        if self.sent_inputs.is_empty() {
+            return;
+        }
+
+        let last_i = self.sent_inputs.len() - 1;
+        self.sent_inputs_cursor = match (self.sent_inputs_cursor, previous) {
+            (None, true) => Some(last_i),
+            (None, false) => None,
+            (Some(cursor), true) => Some(cursor.saturating_sub(1)),
+            (Some(cursor), false) if cursor < last_i => Some(cursor + 1),
+            (Some(_), false) => None,
+        };
+        self.input_value = match self.sent_inputs_cursor {
+            Some(cursor) => self.sent_inputs[cursor].clone(),
+            None => "".to_string(),
+        };
+    }
+
+    pub fn current_channel(&self) -> &Channel {
+        &self.current_channel
+    }
+
+    pub fn set_current_channel(&mut self, channel: Channel) {
+        if self.display {
+            self.unread.remove(&channel);
+        }
+        self.current_channel = channel;
+    }
+
+    pub fn is_muted(&self, character_id: &str) -> bool {
+        self.muted.iter().any(|(id, _)| id == character_id)
+    }
+
+    pub fn muted(&self) -> &[(String, String)] {
+        &self.muted
+    }
+
+    pub fn mute(&mut self, character_id: String, character_name: String) {
+        if !self.is_muted(&character_id) {
+            self.muted.push((character_id, character_name));
+        }
+    }
+
+    pub fn unmute(&mut self, character_id: &str) {
+        self.muted.retain(|(id, _)| id != character_id);
+    }
+
+    pub fn search_value(&self) -> &str {
+        &self.search_value
     }
 
     pub fn input_value(&self) -> &str {
@@ -105,10 +345,34 @@ impl State {
     pub fn update_from_display(&mut self, display_state: &DisplayState) {
         self.input_value = display_state.input_value.clone();
         self.input_focused = display_state.input_focused;
+        self.search_focused = display_state.search_focused;
+        self.search_value = display_state.search_value.clone();
         self.mouse_hover = display_state.mouse_hover;
         if display_state.input_focused {
             self.request_focus = false;
         }
+        if display_state.recall_previous || display_state.recall_next {
+            self.recall_sent_input(display_state.recall_previous);
+        }
+        if let Some(completion) = &display_state.completion {
+            self.input_value = completion.clone();
+            self.request_focus = true;
+        }
+        if let Some(channel) = &display_state.selected_channel {
+            self.set_current_channel(channel.clone());
+        }
+        if let Some((character_id, character_name)) = &display_state.mute {
+            self.mute(character_id.clone(), character_name.clone());
+        }
+        if let Some(character_id) = &display_state.unmute {
+            self.unmute(character_id);
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.save_history(true);
     }
 }
 
@@ -117,7 +381,18 @@ pub struct DisplayState {
     pub input_gained_focus: bool,
     pub input_validated: bool,
     pub input_value: String,
+    pub search_focused: bool,
+    pub search_value: String,
+    // Up / down keys pressed in chat input
+    pub recall_previous: bool,
+    pub recall_next: bool,
+    // Chat input completion chosen by player
+    pub completion: Option<String>,
     pub mouse_hover: bool,
+    pub selected_channel: Option<Channel>,
+    // Character id and name
+    pub mute: Option<(String, String)>,
+    pub unmute: Option<String>,
 }
 
 impl DisplayState {
@@ -127,7 +402,15 @@ impl DisplayState {
             input_gained_focus: false,
             input_validated: false,
             input_value: state.input_value().to_string(),
+            search_focused: state.search_focused,
+            search_value: state.search_value().to_string(),
+            recall_previous: false,
+            recall_next: false,
+            completion: None,
             mouse_hover: false,
+            selected_channel: None,
+            mute: None,
+            unmute: None,
         }
     }
 }
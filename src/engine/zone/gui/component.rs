@@ -78,6 +78,19 @@ impl ProgressBar {
         }
     }
 
+    pub fn percent(&self) -> f32 {
+        self.percent
+    }
+
+    /// Bar filling, as displayed
+    pub fn level(&self) -> f32 {
+        if self.inverted {
+            100. - self.percent
+        } else {
+            self.percent
+        }
+    }
+
     pub fn draw_params(&self, width: f32, height: f32) -> Vec<DrawTextureParams> {
         let progress = if self.inverted {
             (100. - self.percent).max(5.)
@@ -0,0 +1,116 @@
+use crate::{entity::tile::TransportMode, i18n};
+use macroquad::prelude::*;
+
+const INDICATOR_WIDTH: f32 = 64.;
+pub const INDICATOR_HEIGHT: f32 = 20.;
+const INDICATOR_FONT_SIZE: f32 = 16.;
+
+pub fn draw_transport_mode_indicator(
+    transport_mode: &TransportMode,
+    draw_start_y: f32,
+    right_offset: f32,
+) -> bool {
+    let active = transport_mode != &TransportMode::Walking;
+    let name = match transport_mode.name_key() {
+        Some(key) => i18n::tr(key),
+        None => transport_mode.to_string(),
+    };
+    draw_indicator(name, active, draw_start_y, right_offset)
+}
+
+pub fn draw_free_look_indicator(free_look: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.free_look"),
+        free_look,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+pub fn draw_journal_indicator(display: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.journal"),
+        display,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+pub fn draw_characters_indicator(display: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.characters"),
+        display,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+pub fn draw_stats_indicator(display: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.stats"),
+        display,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+pub fn draw_need_alerts_indicator(display: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.alerts"),
+        display,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+pub fn draw_user_log_history_indicator(
+    display: bool,
+    draw_start_y: f32,
+    right_offset: f32,
+) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.messages"),
+        display,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+pub fn draw_settings_indicator(display: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    draw_indicator(
+        i18n::tr("indicator.settings"),
+        display,
+        draw_start_y,
+        right_offset,
+    )
+}
+
+fn draw_indicator(text: &str, active: bool, draw_start_y: f32, right_offset: f32) -> bool {
+    let draw_start_x = screen_width() - INDICATOR_WIDTH - right_offset;
+    let draw_end_x = draw_start_x + INDICATOR_WIDTH;
+    let draw_end_y = draw_start_y + INDICATOR_HEIGHT;
+    let color = if active { SKYBLUE } else { GRAY };
+
+    draw_rectangle(
+        draw_start_x,
+        draw_start_y,
+        INDICATOR_WIDTH,
+        INDICATOR_HEIGHT,
+        color,
+    );
+
+    let text_dimensions = measure_text(text, None, INDICATOR_FONT_SIZE as u16, 1.0);
+    draw_text(
+        text,
+        draw_start_x + (INDICATOR_WIDTH - text_dimensions.width) / 2.,
+        draw_start_y + (INDICATOR_HEIGHT + text_dimensions.height) / 2.,
+        INDICATOR_FONT_SIZE,
+        BLACK,
+    );
+
+    let (mouse_x, mouse_y) = mouse_position();
+    mouse_x >= draw_start_x
+        && mouse_x <= draw_end_x
+        && mouse_y >= draw_start_y
+        && mouse_y <= draw_end_y
+}
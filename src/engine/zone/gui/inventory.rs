@@ -33,11 +33,9 @@ const CLOSE_ICON_HEIGHT: f32 = 32.;
 pub fn draw_close(graphics: &graphics::Graphics) {
     let dest_x = (screen_width() / 2.0) - CLOSE_ICON_WIDTH;
     let dest_y = INVENTORY_BOX_MARGIN - (CLOSE_ICON_HEIGHT * 1.7);
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         dest_x,
         dest_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 CLOSE_ICON_X + 1.,
@@ -59,11 +57,9 @@ pub fn draw_back(
     height: f32,
 ) -> bool {
     let height = height + HELP_TEXT_HEIGHT;
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         dest_x,
         dest_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(BACK_X, BACK_Y, BACK_WIDTH, BACK_HEIGHT)),
             dest_size: Some(Vec2::new(width, height)),
@@ -88,11 +84,9 @@ pub fn draw_item(
     draw_is_equip: bool,
 ) -> bool {
     // Background
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         dest_x,
         dest_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 BUTTON_BACKGROUND_X,
@@ -106,16 +100,14 @@ pub fn draw_item(
     );
 
     // Tile
-    let source = graphics
-        .tiles_mapping
+    let source_rect = graphics
+        .tiles_mapping()
         .get(tile_id)
-        .expect(&format!("Tile id {} is unknown", tile_id));
-    let source_rect = source.to_rect(0);
-    draw_texture_ex(
-        graphics.tileset_texture,
+        .expect(&format!("Tile id {} is unknown", tile_id))
+        .to_rect(0);
+    graphics.draw_tileset_part(
         dest_x,
         dest_y,
-        WHITE,
         DrawTextureParams {
             source: Some(source_rect),
             dest_size: Some(Vec2::new(BUTTON_WIDTH, BUTTON_HEIGHT)),
@@ -129,11 +121,9 @@ pub fn draw_item(
     }
 
     if draw_heavy_icon {
-        draw_texture_ex(
-            graphics.tileset_texture,
+        graphics.draw_tileset_part(
             dest_x + BUTTON_WIDTH - (HEAVY_ICON_WIDTH) + 10.,
             dest_y + BUTTON_HEIGHT - (HEAVY_ICON_HEIGHT) + 10.,
-            WHITE,
             DrawTextureParams {
                 source: Some(Rect::new(
                     HEAVY_ICON_X + 1.,
@@ -147,11 +137,9 @@ pub fn draw_item(
     }
 
     if draw_is_equip {
-        draw_texture_ex(
-            graphics.tileset_texture,
+        graphics.draw_tileset_part(
             dest_x - 10.,
             dest_y + BUTTON_HEIGHT - (EQUIP_ICON_HEIGHT) + 10.,
-            WHITE,
             DrawTextureParams {
                 source: Some(Rect::new(
                     EQUIP_ICON_X + 1.,
@@ -178,11 +166,9 @@ pub fn draw_item(
 }
 
 pub fn draw_more(graphics: &graphics::Graphics, dest_x: f32, dest_y: f32) -> bool {
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         dest_x,
         dest_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(LOADING_X, LOADING_Y, BUTTON_WIDTH, BUTTON_HEIGHT)),
             ..Default::default()
@@ -3,6 +3,7 @@ pub mod blink;
 pub mod button;
 pub mod chat;
 pub mod component;
+pub mod indicator;
 pub mod inventory;
 pub mod panel;
 pub mod quick;
@@ -112,11 +112,9 @@ impl Button {
 }
 
 pub fn draw_panel_background(graphics: &graphics::Graphics) {
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         0.,
         0.,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 TOP_PANEL_X,
@@ -129,11 +127,9 @@ pub fn draw_panel_background(graphics: &graphics::Graphics) {
     );
     let height_count = (screen_height() / MIDDLE_PANEL_HEIGHT as f32) as i32 + 1;
     for i in 0..height_count {
-        draw_texture_ex(
-            graphics.tileset_texture,
+        graphics.draw_tileset_part(
             0.,
             TOP_PANEL_HEIGHT + (MIDDLE_PANEL_HEIGHT as f32 * i as f32) as f32,
-            WHITE,
             DrawTextureParams {
                 source: Some(Rect::new(
                     MIDDLE_PANEL_X,
@@ -145,11 +141,9 @@ pub fn draw_panel_background(graphics: &graphics::Graphics) {
             },
         );
     }
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         0.,
         screen_height() - BOTTOM_PANEL_HEIGHT as f32,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(
                 BOTTOM_PANEL_X,
@@ -251,11 +245,9 @@ pub fn draw_buttons(
             + ((DISPLAY_BUTTON_HEIGHT as f32 + BUTTON_MARGIN) * row_i as f32);
 
         // Draw background
-        draw_texture_ex(
-            graphics.tileset_texture,
+        graphics.draw_tileset_part(
             draw_to_x,
             draw_to_y,
-            WHITE,
             DrawTextureParams {
                 source: Some(Rect::new(
                     BUTTON_BACKGROUND_X,
@@ -281,11 +273,9 @@ pub fn draw_buttons(
             } else {
                 *button_source_rect
             };
-            draw_texture_ex(
-                graphics.tileset_texture,
+            graphics.draw_tileset_part(
                 draw_to_x,
                 draw_to_y,
-                WHITE,
                 DrawTextureParams {
                     source: Some(final_button_source_rect),
                     dest_size: Some(Vec2::new(DISPLAY_BUTTON_WIDTH, DISPLAY_BUTTON_HEIGHT)),
@@ -293,11 +283,9 @@ pub fn draw_buttons(
                 },
             );
         } else {
-            draw_texture_ex(
-                graphics.tileset_texture,
+            graphics.draw_tileset_part(
                 draw_to_x,
                 draw_to_y,
-                WHITE,
                 DrawTextureParams {
                     source: Some(Rect::new(LOADING_X, LOADING_Y, BUTTON_WIDTH, BUTTON_HEIGHT)),
                     dest_size: Some(Vec2::new(DISPLAY_BUTTON_WIDTH, DISPLAY_BUTTON_HEIGHT)),
@@ -23,11 +23,9 @@ pub fn draw_quick_action_button(
     let source_y = BUTTON_Y;
 
     // Draw background
-    draw_texture_ex(
-        graphics.tileset_texture,
+    graphics.draw_tileset_part(
         draw_x,
         draw_y,
-        WHITE,
         DrawTextureParams {
             source: Some(Rect::new(source_x, source_y, BUTTON_WIDTH, BUTTON_HEIGHT)),
             dest_size: Some(Vec2::new(
@@ -39,11 +37,11 @@ pub fn draw_quick_action_button(
     );
 
     // Draw tile 1
-    let tile1_source = graphics
-        .tiles_mapping
+    let tile1_source_rect = graphics
+        .tiles_mapping()
         .get(tile_id1)
-        .expect(&format!("Tile id {} is unknown", tile_id1));
-    let tile1_source_rect = tile1_source.to_rect(tick_i);
+        .expect(&format!("Tile id {} is unknown", tile_id1))
+        .to_rect(tick_i);
 
     let dest_size_x = graphics.tile_width * 1.5 * button_size_factor();
     let dest_size_y = graphics.tile_height * 1.5 * button_size_factor();
@@ -57,20 +55,14 @@ pub fn draw_quick_action_button(
     let tile_draw_x = draw_x + 5.;
     let tile_draw_y = draw_y + 5.;
 
-    draw_texture_ex(
-        graphics.tileset_texture,
-        tile_draw_x,
-        tile_draw_y,
-        WHITE,
-        tile1_params,
-    );
+    graphics.draw_tileset_part(tile_draw_x, tile_draw_y, tile1_params);
 
     // Draw tile 2
-    let tile2_source = graphics
-        .tiles_mapping
+    let tile2_source_rect = graphics
+        .tiles_mapping()
         .get(tile_id2)
-        .expect(&format!("Tile id {} is unknown", tile_id2));
-    let tile2_source_rect = tile2_source.to_rect(tick_i);
+        .expect(&format!("Tile id {} is unknown", tile_id2))
+        .to_rect(tick_i);
 
     let dest_size_x = graphics.tile_width * 1.5 * button_size_factor();
     let dest_size_y = graphics.tile_height * 1.5 * button_size_factor();
@@ -84,13 +76,7 @@ pub fn draw_quick_action_button(
     let tile_draw_x = draw_x + (50. * button_size_factor());
     let tile_draw_y = draw_y + 5.;
 
-    draw_texture_ex(
-        graphics.tileset_texture,
-        tile_draw_x,
-        tile_draw_y,
-        WHITE,
-        tile2_params,
-    );
+    graphics.draw_tileset_part(tile_draw_x, tile_draw_y, tile2_params);
 
     if let Some(quick_action_key_) = quick_action_key {
         draw_circle(draw_x + 5., draw_y + 5., 10., BEIGE);
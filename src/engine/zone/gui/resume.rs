@@ -252,20 +252,8 @@ fn draw_item(
     dest_x: f32,
     dest_y: f32,
 ) {
-    draw_texture_ex(
-        graphics.tileset_texture,
-        dest_x,
-        dest_y,
-        WHITE,
-        type_draw_param,
-    );
+    graphics.draw_tileset_part(dest_x, dest_y, type_draw_param);
     for draw_param in value_draw_params {
-        draw_texture_ex(
-            graphics.tileset_texture,
-            dest_x + WIDTH + MARGIN_RIGHT,
-            dest_y,
-            WHITE,
-            draw_param,
-        );
+        graphics.draw_tileset_part(dest_x + WIDTH + MARGIN_RIGHT, dest_y, draw_param);
     }
 }
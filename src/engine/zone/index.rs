@@ -0,0 +1,82 @@
+use crate::tileset::TileId;
+
+pub struct IndexedEntity<K> {
+    pub id: K,
+    // Tile id resolved once when entity is indexed
+    pub tile_id: TileId,
+}
+
+#[derive(Default)]
+pub struct TileEntities {
+    pub build: Option<IndexedEntity<i32>>,
+    pub resources: Vec<IndexedEntity<String>>,
+    pub stuffs: Vec<IndexedEntity<i32>>,
+    pub character_ids: Vec<String>,
+}
+
+impl TileEntities {
+    pub fn is_empty(&self) -> bool {
+        self.build.is_none()
+            && self.resources.is_empty()
+            && self.stuffs.is_empty()
+            && self.character_ids.is_empty()
+    }
+}
+
+/// Zone entities bucketed by tile, to only look at the tiles which matter (visible area, clicked tile, ...)
+pub struct SpatialIndex {
+    width: usize,
+    height: usize,
+    tiles: Vec<TileEntities>,
+}
+
+impl SpatialIndex {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            tiles: (0..width * height)
+                .map(|_| TileEntities::default())
+                .collect(),
+        }
+    }
+
+    fn offset(&self, row_i: i32, col_i: i32) -> Option<usize> {
+        if row_i < 0 || col_i < 0 || row_i as usize >= self.height || col_i as usize >= self.width {
+            return None;
+        }
+
+        Some(row_i as usize * self.width + col_i as usize)
+    }
+
+    pub fn get(&self, row_i: i32, col_i: i32) -> Option<&TileEntities> {
+        self.offset(row_i, col_i).map(|offset| &self.tiles[offset])
+    }
+
+    pub fn get_mut(&mut self, row_i: i32, col_i: i32) -> Option<&mut TileEntities> {
+        match self.offset(row_i, col_i) {
+            Some(offset) => Some(&mut self.tiles[offset]),
+            None => None,
+        }
+    }
+
+    /// Iterate on tiles (with their coordinates) inside given area ((row_min, col_min), (row_max, col_max))
+    pub fn in_area(
+        &self,
+        draw_area: &((i32, i32), (i32, i32)),
+    ) -> impl Iterator<Item = (i32, i32, &TileEntities)> {
+        let ((row_min, col_min), (row_max, col_max)) = *draw_area;
+        let row_min = row_min.max(0);
+        let col_min = col_min.max(0);
+        let row_max = row_max.min(self.height as i32 - 1);
+        let col_max = col_max.min(self.width as i32 - 1);
+
+        (row_min..=row_max).flat_map(move |row_i| {
+            (col_min..=col_max).filter_map(move |col_i| {
+                self.get(row_i, col_i)
+                    .filter(|tile_entities| !tile_entities.is_empty())
+                    .map(|tile_entities| (row_i, col_i, tile_entities))
+            })
+        })
+    }
+}
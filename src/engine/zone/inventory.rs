@@ -99,8 +99,10 @@ impl super::ZoneEngine {
                                     } else {
                                         super::log::UserLogLevel::Info
                                     };
-                                    self.user_logs
-                                        .push(super::log::UserLog::new(message, message_level));
+                                    self.push_user_log(super::log::UserLog::new(
+                                        message,
+                                        message_level,
+                                    ));
                                 }
                                 self.make_open_inventory_request();
                             }
@@ -304,15 +306,13 @@ impl super::ZoneEngine {
                 if let Some(dragged_stuff_i) = inventory_state.dragging_stuff_i {
                     let stuff = &inventory.stuff[dragged_stuff_i];
                     let tile_id = self.graphics.find_tile_id_from_classes(&stuff.classes);
-                    draw_texture_ex(
-                        self.graphics.tileset_texture,
+                    self.graphics.draw_tileset_part(
                         mouse_position.x,
                         mouse_position.y,
-                        WHITE,
                         DrawTextureParams {
                             source: Some(
                                 self.graphics
-                                    .tiles_mapping
+                                    .tiles_mapping()
                                     .get(&tile_id)
                                     .unwrap()
                                     .to_rect(0),
@@ -323,15 +323,13 @@ impl super::ZoneEngine {
                 } else if let Some(dragged_resource_i) = inventory_state.dragging_resource_i {
                     let resource = &inventory.resource[dragged_resource_i];
                     let tile_id = self.graphics.find_tile_id_from_classes(&resource.classes);
-                    draw_texture_ex(
-                        self.graphics.tileset_texture,
+                    self.graphics.draw_tileset_part(
                         mouse_position.x,
                         mouse_position.y,
-                        WHITE,
                         DrawTextureParams {
                             source: Some(
                                 self.graphics
-                                    .tiles_mapping
+                                    .tiles_mapping()
                                     .get(&tile_id)
                                     .unwrap()
                                     .to_rect(0),
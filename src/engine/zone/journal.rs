@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+
+use egui::Context;
+
+use crate::{i18n, ui::utils::egui_scale, util};
+
+// Oldest entries are removed beyond this count
+const JOURNAL_MAX_ENTRIES: usize = 500;
+const JOURNAL_WINDOW_WIDTH: f32 = 350.;
+const JOURNAL_WINDOW_HEIGHT: f32 = 300.;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JournalEntryKind {
+    CharacterEnter,
+    CharacterExit,
+    NewBuild,
+    RemoveBuild,
+    GroundStuffAdded,
+    GroundStuffRemoved,
+    GroundResourceAdded,
+    GroundResourceRemoved,
+}
+
+impl JournalEntryKind {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::CharacterEnter,
+            Self::CharacterExit,
+            Self::NewBuild,
+            Self::RemoveBuild,
+            Self::GroundStuffAdded,
+            Self::GroundStuffRemoved,
+            Self::GroundResourceAdded,
+            Self::GroundResourceRemoved,
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::CharacterEnter => i18n::tr("journal.character_enter"),
+            Self::CharacterExit => i18n::tr("journal.character_exit"),
+            Self::NewBuild => i18n::tr("journal.new_build"),
+            Self::RemoveBuild => i18n::tr("journal.remove_build"),
+            Self::GroundStuffAdded => i18n::tr("journal.ground_stuff_added"),
+            Self::GroundStuffRemoved => i18n::tr("journal.ground_stuff_removed"),
+            Self::GroundResourceAdded => i18n::tr("journal.ground_resource_added"),
+            Self::GroundResourceRemoved => i18n::tr("journal.ground_resource_removed"),
+        }
+    }
+}
+
+pub struct JournalEntry {
+    pub time: f64,
+    pub kind: JournalEntryKind,
+    // Known when entry concern a character which name can be resolved later
+    pub character_id: Option<String>,
+    pub character_name: Option<String>,
+    pub row_i: i32,
+    pub col_i: i32,
+    pub detail: Option<String>,
+}
+
+impl JournalEntry {
+    pub fn new(
+        kind: JournalEntryKind,
+        character_name: Option<String>,
+        row_i: i32,
+        col_i: i32,
+        detail: Option<String>,
+    ) -> Self {
+        Self {
+            time: util::now(),
+            kind,
+            character_id: None,
+            character_name,
+            row_i,
+            col_i,
+            detail,
+        }
+    }
+
+    pub fn with_character_id(mut self, character_id: &str) -> Self {
+        self.character_id = Some(character_id.to_string());
+        self
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = format!(
+            "{} ({}, {}) {}",
+            util::format_time(self.time),
+            self.row_i,
+            self.col_i,
+            self.kind.name()
+        );
+        if let Some(detail) = &self.detail {
+            text.push_str(&format!(" : {}", detail));
+        }
+        if let Some(character_name) = &self.character_name {
+            text.push_str(&format!(" ({})", character_name));
+        }
+        text
+    }
+}
+
+#[derive(Default)]
+pub struct Journal {
+    entries: VecDeque<JournalEntry>,
+    display: bool,
+    hidden_kinds: Vec<JournalEntryKind>,
+    search: String,
+    mouse_hover: bool,
+}
+
+impl Journal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, entry: JournalEntry) {
+        self.entries.push_back(entry);
+        if self.entries.len() > JOURNAL_MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Set name of entries character which was unknown when they were pushed
+    pub fn resolve_character_name(&mut self, character_id: &str, name: &str) {
+        for entry in self.entries.iter_mut() {
+            if entry.character_name.is_none() && entry.character_id.as_deref() == Some(character_id)
+            {
+                entry.character_name = Some(name.to_string());
+            }
+        }
+    }
+
+    pub fn is_display(&self) -> bool {
+        self.display
+    }
+
+    pub fn set_display(&mut self, display: bool) {
+        self.display = display;
+        if !display {
+            self.mouse_hover = false;
+        }
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    fn is_visible(&self, entry: &JournalEntry) -> bool {
+        if self.hidden_kinds.contains(&entry.kind) {
+            return false;
+        }
+
+        let search = self.search.trim().to_lowercase();
+        search.is_empty() || entry.text().to_lowercase().contains(&search)
+    }
+
+    /// Draw journal window and return clicked entry tile coordinates, if any
+    pub fn ui(&mut self, egui_ctx: &Context) -> Option<(i32, i32)> {
+        let mut clicked = None;
+        let mut display = self.display;
+
+        let response = egui::Window::new(i18n::tr("journal.title"))
+            .open(&mut display)
+            .default_pos((0., 0.))
+            .default_size((JOURNAL_WINDOW_WIDTH, JOURNAL_WINDOW_HEIGHT))
+            .show(egui_ctx, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for kind in JournalEntryKind::all() {
+                        let mut visible = !self.hidden_kinds.contains(&kind);
+                        if ui.checkbox(&mut visible, kind.name()).changed() {
+                            if visible {
+                                self.hidden_kinds.retain(|k| k != &kind);
+                            } else {
+                                self.hidden_kinds.push(kind);
+                            }
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("journal.search"));
+                    ui.text_edit_singleline(&mut self.search);
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .max_height(JOURNAL_WINDOW_HEIGHT / egui_scale())
+                    .show(ui, |ui| {
+                        // Most recent first
+                        for entry in self.entries.iter().rev() {
+                            if !self.is_visible(entry) {
+                                continue;
+                            }
+                            if ui.selectable_label(false, entry.text()).clicked() {
+                                clicked = Some((entry.row_i, entry.col_i));
+                            }
+                        }
+                    });
+            });
+
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        self.set_display(display);
+
+        clicked
+    }
+}
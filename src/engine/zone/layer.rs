@@ -0,0 +1,153 @@
+use macroquad::prelude::*;
+
+use crate::graphics::Graphics;
+
+use super::PlayerRunning;
+
+/// Scene layers, drawn in this order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RenderLayer {
+    Ground,
+    FloorBuilds,
+    GroundItems,
+    // Characters and standing builds, sorted by depth (y position)
+    Actors,
+    Overlays,
+}
+
+pub enum Drawable<'a> {
+    Tile {
+        tile_id: &'a str,
+        background_tile_id: Option<&'a str>,
+        dest_x: f32,
+        dest_y: f32,
+    },
+    // Tile without background, tinted with color
+    ColoredTile {
+        tile_id: &'a str,
+        color: Color,
+        dest_x: f32,
+        dest_y: f32,
+    },
+    // Items count of a ground stack
+    CountBadge {
+        count: u32,
+        dest_x: f32,
+        dest_y: f32,
+    },
+    Character {
+        character_id: &'a str,
+        running: &'a Option<PlayerRunning>,
+        dest_x: f32,
+        dest_y: f32,
+    },
+    // Any other thing to draw in camera (animations, etc.)
+    Custom(Box<dyn Fn(&Graphics) + 'a>),
+}
+
+struct RenderItem<'a> {
+    layer: RenderLayer,
+    depth: f32,
+    drawable: Drawable<'a>,
+}
+
+/// Collect scene drawables to draw them layer by layer, sorted by depth inside each layer.
+/// Drawables with same layer and depth are drawn in push order
+pub struct RenderQueue<'a> {
+    items: Vec<RenderItem<'a>>,
+    concrete_width: f32,
+    concrete_height: f32,
+    tick_i: i16,
+    tick9_i: i16,
+}
+
+impl<'a> RenderQueue<'a> {
+    pub fn new(concrete_width: f32, concrete_height: f32, tick_i: i16, tick9_i: i16) -> Self {
+        Self {
+            items: vec![],
+            concrete_width,
+            concrete_height,
+            tick_i,
+            tick9_i,
+        }
+    }
+
+    /// Push a drawable. Depth is its concrete y position (bottom most is drawn last)
+    pub fn push(&mut self, layer: RenderLayer, depth: f32, drawable: Drawable<'a>) {
+        self.items.push(RenderItem {
+            layer,
+            depth,
+            drawable,
+        });
+    }
+
+    pub fn draw(mut self, graphics: &Graphics) {
+        // Stable sort keep push order for equal layer and depth
+        self.items.sort_by(|a, b| {
+            a.layer
+                .cmp(&b.layer)
+                .then_with(|| a.depth.total_cmp(&b.depth))
+        });
+
+        for item in &self.items {
+            match &item.drawable {
+                Drawable::Tile {
+                    tile_id,
+                    background_tile_id,
+                    dest_x,
+                    dest_y,
+                } => graphics.draw_tile_in_camera(
+                    self.concrete_width,
+                    self.concrete_height,
+                    *dest_x,
+                    *dest_y,
+                    tile_id,
+                    *background_tile_id,
+                    self.tick_i,
+                    None,
+                    None,
+                ),
+                Drawable::ColoredTile {
+                    tile_id,
+                    color,
+                    dest_x,
+                    dest_y,
+                } => graphics.draw_colored_tile_in_camera(
+                    self.concrete_width,
+                    self.concrete_height,
+                    *dest_x,
+                    *dest_y,
+                    tile_id,
+                    self.tick_i,
+                    *color,
+                ),
+                Drawable::CountBadge {
+                    count,
+                    dest_x,
+                    dest_y,
+                } => graphics.draw_count_badge_in_camera(
+                    self.concrete_width,
+                    self.concrete_height,
+                    *dest_x,
+                    *dest_y,
+                    *count,
+                ),
+                Drawable::Character {
+                    character_id,
+                    running,
+                    dest_x,
+                    dest_y,
+                } => graphics.draw_character_in_camera(
+                    self.concrete_width,
+                    self.concrete_height,
+                    *dest_x,
+                    *dest_y,
+                    self.tick9_i,
+                    character_id,
+                    running,
+                ),
+                Drawable::Custom(draw) => draw(graphics),
+            }
+        }
+    }
+}
@@ -114,9 +114,9 @@ impl ZoneEngine {
 
     pub fn draw_player_avatar(&self) {
         let avatar_uuid = self.state.player.private_avatar_uuid();
-        if let Some(avatar_texture) = self.graphics.avatars.get(&avatar_uuid) {
+        if let Some(avatar_texture) = self.graphics.avatar(&avatar_uuid) {
             draw_texture_ex(
-                *avatar_texture,
+                avatar_texture,
                 AVATAR_DRAW_X,
                 AVATAR_DRAW_Y,
                 WHITE,
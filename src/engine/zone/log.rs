@@ -1,6 +1,14 @@
+use std::collections::VecDeque;
+
+use egui::Context;
 use macroquad::prelude::*;
 
-use crate::ui::utils::egui_scale;
+use crate::{
+    animation::{easing::Easing, tween::Tween},
+    i18n,
+    ui::utils::egui_scale,
+    util,
+};
 
 use super::gui::chat::display::Display as ChatDisplay;
 
@@ -9,12 +17,23 @@ pub const LOG_LINE_HEIGHT: f32 = 20.0;
 pub const LOG_LINE_FONT_SIZE: f32 = 20.0;
 pub const LOG_BOX_HEIGHT: f32 = super::DISPLAY_USER_LOG_COUNT as f32 * LOG_LINE_HEIGHT;
 pub const LOG_BOX_WIDTH: f32 = 230.0;
+// Oldest history entries are removed beyond this count
+const LOG_HISTORY_MAX_ENTRIES: usize = 2000;
+const LOG_HISTORY_WINDOW_WIDTH: f32 = 400.;
+const LOG_HISTORY_WINDOW_HEIGHT: f32 = 300.;
+// New log lines slide in from the left while fading in
+const LOG_LINE_TRANSITION_DURATION: f32 = 0.3;
+const LOG_LINE_TRANSITION_SLIDE: f32 = 20.;
 
+#[derive(Clone)]
 pub struct UserLog {
     level: UserLogLevel,
     message: String,
+    // Timestamp (seconds since epoch)
+    time: f64,
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum UserLogLevel {
     Info,
     Error,
@@ -25,12 +44,14 @@ impl UserLog {
         Self {
             level: UserLogLevel::Info,
             message,
+            time: util::now(),
         }
     }
     pub fn error(message: String) -> Self {
         Self {
             level: UserLogLevel::Error,
             message,
+            time: util::now(),
         }
     }
 
@@ -38,6 +59,25 @@ impl UserLog {
         Self {
             level: message_level,
             message,
+            time: util::now(),
+        }
+    }
+
+    fn text(&self) -> String {
+        format!(
+            "[{}] {} : {}",
+            util::format_time(self.time),
+            self.level,
+            self.message
+        )
+    }
+}
+
+impl UserLogLevel {
+    pub fn name(&self) -> &str {
+        match self {
+            UserLogLevel::Info => i18n::tr("log.info"),
+            UserLogLevel::Error => i18n::tr("log.error"),
         }
     }
 }
@@ -51,7 +91,107 @@ impl std::fmt::Display for UserLogLevel {
     }
 }
 
+/// Every user log of the session, with a window to browse them. Owned by main loop to survive zone
+/// engine recreations (zone change, reconnection)
+#[derive(Default)]
+pub struct UserLogHistory {
+    entries: VecDeque<UserLog>,
+    hidden_levels: Vec<UserLogLevel>,
+    display: bool,
+    mouse_hover: bool,
+}
+
+impl UserLogHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, user_log: UserLog) {
+        self.entries.push_back(user_log);
+        if self.entries.len() > LOG_HISTORY_MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn is_display(&self) -> bool {
+        self.display
+    }
+
+    pub fn set_display(&mut self, display: bool) {
+        self.display = display;
+        if !display {
+            self.mouse_hover = false;
+        }
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    fn visible_entries(&self) -> impl Iterator<Item = &UserLog> {
+        self.entries
+            .iter()
+            .filter(|entry| !self.hidden_levels.contains(&entry.level))
+    }
+
+    pub fn ui(&mut self, egui_ctx: &Context) {
+        let mut display = self.display;
+
+        let response = egui::Window::new(i18n::tr("log.title"))
+            .open(&mut display)
+            .default_size((LOG_HISTORY_WINDOW_WIDTH, LOG_HISTORY_WINDOW_HEIGHT))
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    for level in [UserLogLevel::Info, UserLogLevel::Error] {
+                        let mut visible = !self.hidden_levels.contains(&level);
+                        if ui.checkbox(&mut visible, level.name()).changed() {
+                            if visible {
+                                self.hidden_levels.retain(|l| l != &level);
+                            } else {
+                                self.hidden_levels.push(level);
+                            }
+                        }
+                    }
+                    if ui.button(i18n::tr("log.copy")).clicked() {
+                        let text = self
+                            .visible_entries()
+                            .map(|entry| entry.text())
+                            .collect::<Vec<String>>()
+                            .join("\n");
+                        ui.output().copied_text = text;
+                    }
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .max_height(LOG_HISTORY_WINDOW_HEIGHT / egui_scale())
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        for entry in self.visible_entries() {
+                            let color = match entry.level {
+                                UserLogLevel::Info => ui.visuals().text_color(),
+                                UserLogLevel::Error => egui::Color32::RED,
+                            };
+                            ui.colored_label(color, entry.text());
+                        }
+                    });
+            });
+
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        self.set_display(display);
+    }
+}
+
 impl super::ZoneEngine {
+    /// Display user log and keep it in session history
+    pub fn push_user_log(&mut self, user_log: UserLog) {
+        self.user_log_history.borrow_mut().push(user_log.clone());
+        self.user_logs.push(user_log);
+    }
+
     pub fn draw_user_logs(&self) {
         let bottom_offset = if self.chat_state.is_display() {
             let chat_display = ChatDisplay::from_env();
@@ -76,13 +216,23 @@ impl super::ZoneEngine {
 
         let start_draw_message_x = draw_log_box_x + 10.;
         let start_draw_message_y = draw_log_box_y + 15.;
+        let slide = Tween::new(
+            -LOG_LINE_TRANSITION_SLIDE,
+            0.,
+            LOG_LINE_TRANSITION_DURATION,
+            Easing::QuadOut,
+        );
+        let alpha = Tween::new(0., 1., LOG_LINE_TRANSITION_DURATION, Easing::Linear);
+        let now = util::now();
         for (i, user_log) in self.user_logs.iter().rev().enumerate() {
-            let draw_x = start_draw_message_x;
+            let elapsed = (now - user_log.time) as f32;
+            let draw_x = start_draw_message_x + slide.value_at(elapsed);
             let draw_y = start_draw_message_y + (i as f32 * LOG_LINE_HEIGHT);
-            let color = match user_log.level {
+            let mut color = match user_log.level {
                 UserLogLevel::Info => BLACK,
                 UserLogLevel::Error => RED,
             };
+            color.a = alpha.value_at(elapsed);
             draw_text(&user_log.message, draw_x, draw_y, LOG_LINE_FONT_SIZE, color);
         }
     }
@@ -1,12 +1,15 @@
+use std::{cell::RefCell, rc::Rc};
+
 use macroquad::prelude::*;
 use quad_net::web_socket::WebSocket;
 
 use crate::{
     action as base_action, animation, client, config, description,
-    engine::zone::util::live_message_event,
+    engine::zone::util::{live_message_event, private_message_event},
     entity::{self, description::RequestClicks},
     event as base_event, graphics,
     message::{self, MainMessage},
+    settings::Settings,
     ui::{
         text_input::TextInputRequest,
         utils::{egui_scale, is_mobile, open_url},
@@ -17,7 +20,11 @@ use crate::{
 use self::{
     debug::DebugInfo,
     gui::{
-        blink::BlinkingIcon, chat::display::Display as ChatDisplay, chat::state::State as ChatState,
+        blink::BlinkingIcon,
+        chat::command::{self, ChatCommandContext, ChatCommandEffect},
+        chat::display::Display as ChatDisplay,
+        chat::model::Channel,
+        chat::state::State as ChatState,
     },
     resume::CharacterResume,
 };
@@ -25,25 +32,35 @@ use self::{
 use super::Engine;
 
 pub mod action;
+pub mod alert;
 pub mod animations;
 pub mod blink;
+pub mod bubble;
+pub mod camera;
+pub mod characters;
 pub mod click;
+pub mod construction;
+pub mod context;
 pub mod debug;
 pub mod event;
 pub mod gui;
+pub mod index;
 pub mod inventory;
+pub mod journal;
+pub mod layer;
 pub mod left_panel;
 pub mod log;
+pub mod movement;
+pub mod options;
 pub mod resume;
 pub mod scene;
 pub mod socket;
+pub mod stack;
 pub mod state;
+pub mod stats;
 pub mod ui;
 pub mod util;
 
-const DEFAULT_PLAYER_VELOCITY_DIVIDER: f32 = 2.5;
-const DEFAULT_PLAYER_VELOCITY_LIMIT: f32 = 2.0;
-const RUNNING_PLAYER_VELOCITY_LIMIT: f32 = 5.0;
 const LEFT_PANEL_WIDTH: f32 = 250.;
 const QUICK_ACTION_MARGIN: f32 = 10.;
 pub const DISPLAY_USER_LOG_COUNT: usize = 5;
@@ -74,14 +91,16 @@ pub struct ZoneEngine {
     pub tick_i: i16,
     pub tick9_i: i16,
     pub frame_i: i64,
-    pub zoom_mode: ZoomMode,
+    pub settings: Settings,
+    pub settings_window: options::SettingsWindow,
+    pub camera: camera::Camera,
     pub camera_animations: Vec<Box<dyn animation::Animation>>,
     pub ui_animations: Vec<Box<dyn animation::Animation>>,
-    pub last_limited_user_input: f64,
     pub disable_all_user_input_until: f64,
     pub disable_all_user_input: bool,
     pub user_inputs: Vec<UserInput>,
     pub running_mode: bool,
+    pub movement_timestep: movement::FixedTimestep,
     pub last_require_around_coordinate: (i32, i32),
     pub quick_actions: Vec<base_action::quick::QuickAction>,
     pub selected_quick_action: Option<usize>,
@@ -91,6 +110,7 @@ pub struct ZoneEngine {
     pub mouse_zone_coordinates: (usize, usize),
     pub quick_action_requests: Vec<quad_net::http_request::Request>,
     pub user_logs: Vec<log::UserLog>,
+    pub user_log_history: Rc<RefCell<log::UserLogHistory>>,
     pub helper_text: Option<String>,
     pub description_request: Option<quad_net::http_request::Request>,
     pub current_left_panel_button: Option<gui::panel::Button>,
@@ -98,6 +118,13 @@ pub struct ZoneEngine {
     pub current_description_state: Option<description::UiDescriptionState>,
     pub inventory_request: Option<quad_net::http_request::Request>,
     pub inventory_drop_request: Option<quad_net::http_request::Request>,
+    // Player character refresh (to follow its transport mode)
+    pub player_character_request: Option<quad_net::http_request::Request>,
+    // Zone characters refresh (to know names of entering characters)
+    pub zone_characters_request: Option<quad_net::http_request::Request>,
+    // Build names (by build type) resolved from build descriptions, for construction tooltip
+    pub build_names: ahash::AHashMap<String, String>,
+    pub build_name_request: Option<(String, quad_net::http_request::Request)>,
     pub inventory: Option<inventory::Inventory>,
     pub inventory_state: Option<inventory::InventoryState>,
     pub last_begin_click_coordinates: Option<Vec2>,
@@ -109,10 +136,22 @@ pub struct ZoneEngine {
     pub request_clicks: Option<RequestClicks>,
     pub pending_request_clicks: Option<(RequestClicks, i32, i32)>,
     pub click_begin_in_quick_action: Option<(f32, f32)>,
+    // Build id of construction on which current left click began
+    pub click_begin_on_construction: Option<i32>,
+    // Tile of ground stack on which current left click began
+    pub click_begin_on_ground_stack: Option<(i32, i32)>,
     pub quick_action_x_offset: Option<f32>,
     pub debug_info: DebugInfo,
     pub display_debug_info: bool,
     pub top_left_corner_click_counter: i32,
+    pub journal: journal::Journal,
+    pub characters_panel: characters::CharactersPanel,
+    pub ground_stack: stack::GroundStack,
+    pub context_menu: context::ContextMenu,
+    pub speech_bubbles: bubble::SpeechBubbles,
+    pub stats_history: stats::StatsHistory,
+    pub need_alerts: alert::NeedAlerts,
+    pub followed_character_id: Option<String>,
     chat_state: ChatState,
     chat_text_input_request: Option<TextInputRequest>,
 }
@@ -121,13 +160,15 @@ impl ZoneEngine {
     pub fn new(
         client: client::Client,
         graphics: graphics::Graphics,
-        state: state::ZoneState,
+        mut state: state::ZoneState,
+        user_log_history: Rc<RefCell<log::UserLogHistory>>,
     ) -> Result<Self, String> {
-        let zoom_mode = if is_mobile() {
-            ZoomMode::Double
-        } else {
-            ZoomMode::Normal
-        };
+        let settings = Settings::load();
+        state.update_autotiles(&graphics.tiles_mapping(), settings.terrain_transitions);
+        let camera = camera::Camera::new(state.player_display.draw_position(), &settings);
+        let chat_state = ChatState::new(&state.player.id);
+        let stats_history = stats::StatsHistory::new(&state.player.id);
+        let need_alerts = alert::NeedAlerts::new(&state.player.id);
         Ok(Self {
             client,
             graphics,
@@ -138,14 +179,16 @@ impl ZoneEngine {
             tick_i: 0,
             tick9_i: 0,
             frame_i: 0,
-            zoom_mode,
+            settings,
+            settings_window: options::SettingsWindow::new(),
+            camera,
             camera_animations: vec![],
             ui_animations: vec![],
-            last_limited_user_input: get_time(),
             disable_all_user_input_until: get_time(),
             disable_all_user_input: false,
             user_inputs: vec![],
             running_mode: false,
+            movement_timestep: movement::FixedTimestep::default(),
             last_require_around_coordinate: (0, 0),
             quick_actions: vec![],
             selected_quick_action: None,
@@ -155,6 +198,7 @@ impl ZoneEngine {
             mouse_zone_coordinates: (0, 0),
             quick_action_requests: vec![],
             user_logs: vec![],
+            user_log_history,
             helper_text: None,
             description_request: None,
             current_left_panel_button: None,
@@ -162,6 +206,10 @@ impl ZoneEngine {
             current_description_state: None,
             inventory_request: None,
             inventory_drop_request: None,
+            player_character_request: None,
+            zone_characters_request: None,
+            build_names: ahash::AHashMap::new(),
+            build_name_request: None,
             inventory: None,
             inventory_state: None,
             last_begin_click_coordinates: None,
@@ -173,11 +221,21 @@ impl ZoneEngine {
             request_clicks: None,
             pending_request_clicks: None,
             click_begin_in_quick_action: None,
+            click_begin_on_construction: None,
+            click_begin_on_ground_stack: None,
             quick_action_x_offset: None,
             debug_info: DebugInfo::new(),
             display_debug_info: false,
             top_left_corner_click_counter: 0,
-            chat_state: ChatState::new(),
+            journal: journal::Journal::new(),
+            characters_panel: characters::CharactersPanel::new(),
+            ground_stack: stack::GroundStack::default(),
+            context_menu: context::ContextMenu::new(),
+            speech_bubbles: bubble::SpeechBubbles::new(),
+            stats_history,
+            need_alerts,
+            followed_character_id: None,
+            chat_state,
             chat_text_input_request: None,
         })
     }
@@ -215,40 +273,122 @@ impl ZoneEngine {
 
     fn update(&mut self) {
         let mouse_position = mouse_position();
-        // Player movements
-        // TODO: player moves depending on the zone tiles
-        let mut player_acceleration =
-            -self.state.player_display.velocity / DEFAULT_PLAYER_VELOCITY_DIVIDER;
-        let mut player_running: Option<PlayerRunning> = None;
+        // Player movements wanted this frame
+        let mut player_input = Vec2::new(0., 0.);
 
         while let Some(user_input) = self.user_inputs.pop() {
             match user_input {
                 UserInput::MovePlayerBy(vector) => {
-                    player_acceleration += vector;
+                    player_input += vector;
                 }
-                UserInput::ZoomIn => {
-                    self.zoom_mode = match self.zoom_mode {
-                        ZoomMode::Normal => ZoomMode::Double,
-                        ZoomMode::Double => ZoomMode::Double,
-                        ZoomMode::Large => ZoomMode::Normal,
+                UserInput::ZoomBy(factor) => self.camera.zoom_by(factor),
+                UserInput::SwitchZoom => {
+                    if self.camera.is_zoomed_in() {
+                        self.camera.reset_zoom()
+                    } else {
+                        self.camera.zoom_in_max()
                     }
                 }
-                UserInput::ZoomOut => {
-                    self.zoom_mode = match self.zoom_mode {
-                        ZoomMode::Normal => ZoomMode::Large,
-                        ZoomMode::Double => ZoomMode::Normal,
-                        ZoomMode::Large => ZoomMode::Large,
-                    }
+                UserInput::MoveCameraBy(screen_delta) => self.camera.move_by(screen_delta),
+                UserInput::SwitchFreeLook => {
+                    self.followed_character_id = None;
+                    self.camera.set_free_look(!self.camera.is_free_look())
+                }
+                UserInput::CenterCameraOn(row_i, col_i) => {
+                    // Stay on this tile instead of following player
+                    self.followed_character_id = None;
+                    self.camera.set_free_look(true);
+                    self.camera.center_on(Vec2::new(
+                        col_i as f32 * self.graphics.tile_width,
+                        row_i as f32 * self.graphics.tile_height,
+                    ));
+                }
+                UserInput::SwitchJournal => self.journal.set_display(!self.journal.is_display()),
+                UserInput::SwitchStatsPanel => self
+                    .stats_history
+                    .set_display(!self.stats_history.is_display()),
+                UserInput::SwitchUserLogHistory => {
+                    let mut user_log_history = self.user_log_history.borrow_mut();
+                    let display = user_log_history.is_display();
+                    user_log_history.set_display(!display);
+                }
+                UserInput::SwitchSettingsWindow => self
+                    .settings_window
+                    .set_display(!self.settings_window.is_display()),
+                UserInput::SwitchNeedAlertsSettings => self
+                    .need_alerts
+                    .set_display_settings(!self.need_alerts.is_display_settings()),
+                UserInput::SwitchCharactersPanel => self
+                    .characters_panel
+                    .set_display(!self.characters_panel.is_display()),
+                UserInput::FollowCharacter(character_id) => {
+                    self.followed_character_id = character_id;
+                    self.camera.set_free_look(false);
+                }
+                UserInput::OpenBuildDescription(build_id) => {
+                    self.description_request = Some(self.client.get_description_request(
+                        self.build_description_url(build_id),
+                        None,
+                        None,
+                    ));
+                }
+                UserInput::OpenGroundStack(row_i, col_i) => self.ground_stack.open(row_i, col_i),
+                UserInput::OpenContextMenu(row_i, col_i, screen_position) => {
+                    let entries = self.context_menu_entries(row_i, col_i);
+                    self.context_menu
+                        .open(&self.client, row_i, col_i, screen_position, entries)
                 }
                 UserInput::SwitchRunningMode => self.running_mode = !self.running_mode,
                 UserInput::InRunningMode => self.running_mode = true,
                 UserInput::InWalkingMode => self.running_mode = false,
                 UserInput::SubmitChatInput => {
-                    info!("Send chat message");
-                    web_socket(&self.state).send_text(&live_message_event(
-                        &self.state.player.id,
-                        self.chat_state.input_value().to_string(),
-                    ));
+                    let input = self.chat_state.input_value().to_string();
+                    let effect = command::interpret(&input, &self.chat_command_context());
+                    match effect {
+                        ChatCommandEffect::Send(message) => match self.chat_state.current_channel()
+                        {
+                            Channel::Private(to_character_id) => {
+                                info!("Send private chat message");
+                                web_socket(&self.state).send_text(&private_message_event(
+                                    &self.state.player.id,
+                                    to_character_id,
+                                    message,
+                                ));
+                            }
+                            _ => {
+                                info!("Send chat message");
+                                web_socket(&self.state)
+                                    .send_text(&live_message_event(&self.state.player.id, message));
+                            }
+                        },
+                        ChatCommandEffect::SendZone(message) => {
+                            info!("Send chat message");
+                            web_socket(&self.state)
+                                .send_text(&live_message_event(&self.state.player.id, message));
+                        }
+                        ChatCommandEffect::SendPrivate(
+                            to_character_id,
+                            to_character_name,
+                            message,
+                        ) => {
+                            info!("Send private chat message");
+                            web_socket(&self.state).send_text(&private_message_event(
+                                &self.state.player.id,
+                                &to_character_id,
+                                message,
+                            ));
+                            self.chat_state
+                                .open_conversation(&to_character_id, &to_character_name);
+                            self.chat_state
+                                .set_current_channel(Channel::Private(to_character_id));
+                        }
+                        ChatCommandEffect::ClearChannel => self.chat_state.clear_current_channel(),
+                        ChatCommandEffect::SwitchChannel(channel) => {
+                            self.chat_state.set_current_channel(channel)
+                        }
+                        ChatCommandEffect::Info(text) => self.chat_state.add_info(text),
+                    }
+                    self.chat_state.push_sent_input(input);
                     self.chat_state.reset_input_value();
                     if !is_mobile() {
                         self.chat_state.set_request_focus();
@@ -257,85 +397,7 @@ impl ZoneEngine {
             }
         }
 
-        // Update player velocity and limit its maximum speed
-        let player_velocity_limit = if self.running_mode {
-            RUNNING_PLAYER_VELOCITY_LIMIT
-        } else {
-            DEFAULT_PLAYER_VELOCITY_LIMIT
-        };
-        self.state.player_display.velocity += player_acceleration;
-        if self.state.player_display.velocity.length() > player_velocity_limit {
-            self.state.player_display.velocity =
-                self.state.player_display.velocity.normalize() * player_velocity_limit;
-        }
-
-        let velocity_limiter = self.state.map.get_speed(
-            self.state.player.zone_row_i as usize,
-            self.state.player.zone_col_i as usize,
-            &entity::tile::TransportMode::Walking,
-        );
-        self.state.player_display.velocity *= velocity_limiter;
-
-        // Update player position according to its velocity
-        let next_position = self.state.player_display.position + self.state.player_display.velocity;
-
-        // Update player running animation
-        let was_running = self.state.player_display.running.is_some();
-        if self.state.player_display.velocity.length() > 0.05 {
-            player_running = if self.state.player_display.velocity.y < -0.05 {
-                Some(PlayerRunning::Top)
-            } else if self.state.player_display.velocity.y > 0.05 {
-                Some(PlayerRunning::Down)
-            } else if self.state.player_display.velocity.x > 0.05 {
-                Some(PlayerRunning::Right)
-            } else if self.state.player_display.velocity.x < -0.05 {
-                Some(PlayerRunning::Left)
-            } else {
-                None
-            };
-        }
-
-        if was_running && player_running.is_none() {
-            let coordinates = (self.state.player.zone_row_i, self.state.player.zone_col_i);
-            if coordinates != self.last_require_around_coordinate {
-                let event = util::require_around_event(&self.state);
-                web_socket(&self.state).send_text(&event);
-                self.last_require_around_coordinate = coordinates;
-            }
-        }
-
-        self.state.player_display.running = player_running;
-
-        // Update player zone coordinates if changed
-        let half_size_width = self.graphics.tile_width / 2.;
-        let half_size_height = self.graphics.tile_height / 2.;
-        let next_player_center_x = next_position.x + half_size_width;
-        let next_player_center_y = next_position.y + half_size_height;
-
-        let next_player_row_i = (next_player_center_y / self.graphics.tile_height) as i32;
-        let next_player_col_i = (next_player_center_x / self.graphics.tile_width) as i32;
-
-        let tile_is_traversable = self.state.map.traversable(
-            next_player_row_i as usize,
-            next_player_col_i as usize,
-            &entity::tile::TransportMode::Walking,
-        );
-        let possible_build_is_traversable = self.possible_build_is_traversable(
-            next_player_row_i as usize,
-            next_player_col_i as usize,
-            &entity::tile::TransportMode::Walking,
-        );
-        if tile_is_traversable && possible_build_is_traversable {
-            self.state.player_display.position = next_position;
-            if next_player_row_i != self.state.player.zone_row_i
-                || next_player_col_i != self.state.player.zone_col_i
-            {
-                self.state.player.zone_row_i = next_player_row_i;
-                self.state.player.zone_col_i = next_player_col_i;
-                let player_move_event = util::player_move_event(&self.state);
-                web_socket(&self.state).send_text(&player_move_event);
-            }
-        }
+        self.update_player_movement(player_input);
 
         // User logs
         if self.user_logs.len() > DISPLAY_USER_LOG_COUNT {
@@ -384,6 +446,10 @@ impl ZoneEngine {
 
     fn proceed_quick_action_requests(&mut self) {
         let mut to_removes: Vec<usize> = vec![];
+        // User logs and harvest texts (and their tile), displayed once requests are no more
+        // borrowed
+        let mut user_logs: Vec<log::UserLog> = vec![];
+        let mut harvest_texts: Vec<(String, i32, i32)> = vec![];
 
         for (i, request) in self.quick_action_requests.iter_mut().enumerate() {
             if let Some(data) = request.try_recv() {
@@ -408,8 +474,7 @@ impl ZoneEngine {
                                 };
                                 info!("Quick action response ({}) : {}", &message_level, &message,);
 
-                                self.user_logs
-                                    .push(log::UserLog::new(message.clone(), message_level));
+                                user_logs.push(log::UserLog::new(message.clone(), message_level));
 
                                 // Clean exploitable tile blinking
                                 if let (Some(current_action), Some(action_uuid)) =
@@ -434,6 +499,11 @@ impl ZoneEngine {
                                                     == exploited_tile_position.1
                                             {
                                                 self.pending_exploitable_tiles.retain(|x| x != &i);
+                                                harvest_texts.push((
+                                                    message.clone(),
+                                                    exploitable_tile.zone_row_i,
+                                                    exploitable_tile.zone_col_i,
+                                                ));
 
                                                 let tile_id =
                                                     self.graphics.find_tile_id_from_classes(
@@ -509,6 +579,87 @@ impl ZoneEngine {
         for request_i_to_remove in to_removes {
             self.quick_action_requests.remove(request_i_to_remove);
         }
+
+        for user_log in user_logs {
+            self.push_user_log(user_log);
+        }
+
+        for (text, row_i, col_i) in harvest_texts {
+            let dest = self.zone_position_to_screen_position(row_i as f32 - 1., col_i as f32 + 0.5);
+            self.ui_animations
+                .push(Box::new(animation::text::FloatingTextAnimation::new(
+                    text, dest, WHITE,
+                )));
+        }
+    }
+
+    fn proceed_player_character_request(&mut self) {
+        if let Some(request) = self.player_character_request.as_mut() {
+            if let Some(data) = request.try_recv() {
+                match data {
+                    Ok(character_string) => {
+                        match serde_json::from_str::<entity::character::Character>(
+                            &character_string,
+                        ) {
+                            Ok(character) => {
+                                self.state.player.transport_mode = character.transport_mode;
+                            }
+                            Err(error) => {
+                                error!("Error while decoding player character : {}", error);
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        error!("Error while requiring player character : {}", error);
+                    }
+                }
+                self.player_character_request = None;
+            }
+        }
+    }
+
+    fn proceed_zone_characters_request(&mut self) {
+        if let Some(request) = self.zone_characters_request.as_mut() {
+            if let Some(data) = request.try_recv() {
+                match data {
+                    Ok(characters_string) => {
+                        match serde_json::from_str::<Vec<entity::character::Character>>(
+                            &characters_string,
+                        ) {
+                            Ok(characters) => {
+                                for character in characters {
+                                    if !character.name.is_empty() {
+                                        self.chat_state
+                                            .resolve_sender_name(&character.id, &character.name);
+                                    }
+                                    if let Some(known) =
+                                        self.state.characters.get_mut(&character.id)
+                                    {
+                                        if known.name.is_empty() {
+                                            known.name = character.name.clone();
+                                            known.avatar_uuid = character.avatar_uuid;
+                                            known.avatar_is_validated =
+                                                character.avatar_is_validated;
+                                            self.journal.resolve_character_name(
+                                                &character.id,
+                                                &character.name,
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                            Err(error) => {
+                                error!("Error while decoding zone characters : {}", error);
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        error!("Error while requiring zone characters : {}", error);
+                    }
+                }
+                self.zone_characters_request = None;
+            }
+        }
     }
 
     fn proceed_description_requests(&mut self) -> Vec<message::MainMessage> {
@@ -568,6 +719,19 @@ impl ZoneEngine {
         vec![]
     }
 
+    /// Mouse is over one of the egui windows drawn over the zone
+    pub fn is_mouse_over_window(&self) -> bool {
+        self.chat_state.is_mouse_hover()
+            || self.journal.is_mouse_hover()
+            || self.characters_panel.is_mouse_hover()
+            || self.ground_stack.is_mouse_hover()
+            || self.context_menu.is_mouse_hover()
+            || self.stats_history.is_mouse_hover()
+            || self.need_alerts.is_mouse_hover()
+            || self.user_log_history.borrow().is_mouse_hover()
+            || self.settings_window.is_mouse_hover()
+    }
+
     fn user_inputs(&mut self) {
         if self.disable_all_user_input_until > get_time()
             || self.disable_all_user_input
@@ -575,8 +739,78 @@ impl ZoneEngine {
             || self.inventory.is_some()
             || self.request_clicks.is_some()
             || self.chat_state.is_input_focused()
-            || self.chat_state.is_mouse_hover()
+            || self.is_mouse_over_window()
+        {
+            return;
+        }
+
+        if is_key_pressed(construction::CONTINUE_CONSTRUCTION_KEY) {
+            if let Some(build) = self.hovered_construction() {
+                self.user_inputs
+                    .push(UserInput::OpenBuildDescription(build.id));
+            }
+        }
+        let long_pressed = is_mobile() && self.context_menu.long_pressed();
+        let long_press_triggered = is_mobile() && self.context_menu.is_long_press_triggered();
+        if is_mouse_button_released(MouseButton::Right) || long_pressed {
+            let (row_i, col_i) = self.mouse_zone_coordinates;
+            self.user_inputs.push(UserInput::OpenContextMenu(
+                row_i as i32,
+                col_i as i32,
+                Vec2::from(mouse_position()),
+            ));
+        }
+        // Click (or tap) on a construction continue it instead of moving player
+        if is_mouse_button_pressed(MouseButton::Left) && self.current_action.is_none() {
+            self.click_begin_on_construction = self.hovered_construction().map(|build| build.id);
+        }
+        if base_util::mouse_clicked() {
+            if let Some(build_id) = self.click_begin_on_construction.take() {
+                if self.hovered_construction().map(|build| build.id) == Some(build_id)
+                    && !long_press_triggered
+                {
+                    self.user_inputs
+                        .push(UserInput::OpenBuildDescription(build_id));
+                }
+            }
+        }
+        // Click on a ground stack open it instead of moving player
+        if is_mouse_button_pressed(MouseButton::Left) && self.current_action.is_none() {
+            let (row_i, col_i) = self.mouse_zone_coordinates;
+            self.click_begin_on_ground_stack = Some((row_i as i32, col_i as i32))
+                .filter(|(row_i, col_i)| self.ground_items_count(*row_i, *col_i) > 1);
+        }
+        if base_util::mouse_clicked() {
+            if let Some((row_i, col_i)) = self.click_begin_on_ground_stack.take() {
+                let (mouse_row_i, mouse_col_i) = self.mouse_zone_coordinates;
+                if (mouse_row_i as i32, mouse_col_i as i32) == (row_i, col_i)
+                    && !long_press_triggered
+                {
+                    self.user_inputs
+                        .push(UserInput::OpenGroundStack(row_i, col_i));
+                }
+            }
+        }
+
+        // Camera inputs
+        if let Some(factor) = self.camera.zoom_input() {
+            self.user_inputs.push(UserInput::ZoomBy(factor));
+        }
+        if let Some(factor) = self.camera.pinch_input() {
+            self.user_inputs.push(UserInput::ZoomBy(factor));
+        }
+        if is_key_pressed(camera::FREE_LOOK_KEY)
+            || (self.camera.is_free_look() && is_key_pressed(KeyCode::Escape))
         {
+            self.user_inputs.push(UserInput::SwitchFreeLook);
+        }
+        if self.camera.is_free_look() {
+            let camera_move = self.camera.free_look_input();
+            if camera_move != Vec2::new(0., 0.) {
+                self.user_inputs.push(UserInput::MoveCameraBy(camera_move));
+            }
+
+            // Player don't move while looking around
             return;
         }
 
@@ -604,25 +838,24 @@ impl ZoneEngine {
             self.user_inputs.push(UserInput::InWalkingMode);
         }
 
-        // Keyboard inputs with repetition limitation
-        if get_time() - self.last_limited_user_input > 0.5 {
-            if is_key_down(KeyCode::I) {
-                self.user_inputs.push(UserInput::ZoomIn);
-                self.last_limited_user_input = get_time();
-            }
-            if is_key_down(KeyCode::O) {
-                self.user_inputs.push(UserInput::ZoomOut);
-                self.last_limited_user_input = get_time();
-            }
-        }
-
         // Mouse inputs
-        let (pixels_x, pixels_y) = mouse_position();
-        if is_mouse_button_down(MouseButton::Left) {
+        if is_mouse_button_down(MouseButton::Left) && !self.camera.is_pinching() {
             if
             // Avoid player move by click if currently in action
-            self.current_action.is_none() && self.click_begin_in_quick_action.is_none() {
-                let position_local = base_util::convert_to_local(Vec2::new(pixels_x, pixels_y));
+            self.current_action.is_none()
+                && self.click_begin_in_quick_action.is_none()
+                && self.click_begin_on_construction.is_none()
+                && self.click_begin_on_ground_stack.is_none()
+                // Touch may become a long press (context menu)
+                && !(is_mobile() && self.context_menu.is_long_press_pending())
+            {
+                // Player is not always at screen center (camera easing or zone border)
+                let player_screen_position = self.concrete_position_to_screen_position(
+                    self.state.player_display.draw_position(),
+                );
+                let half_screen = Vec2::new(screen_width() / 2., screen_height() / 2.);
+                let position_local =
+                    (Vec2::from(mouse_position()) - player_screen_position) / half_screen;
                 self.user_inputs
                     .push(UserInput::MovePlayerBy(position_local * 4.5));
             }
@@ -630,29 +863,59 @@ impl ZoneEngine {
     }
 
     fn camera(&mut self) -> ((i32, i32), (i32, i32)) {
+        // Tiles are drawn one tile upper than their row index (camera is Y inverted)
+        let zone_bounds = Rect::new(
+            0.,
+            -self.graphics.tile_height,
+            self.state.map.concrete_width,
+            self.state.map.concrete_height,
+        );
+        // Camera can follow an other character than player
+        let followed_position = self
+            .followed_character_id
+            .as_ref()
+            .and_then(|character_id| self.state.characters.get(character_id))
+            .map(|character| {
+                Vec2::new(
+                    character.zone_col_i as f32 * self.graphics.tile_width,
+                    character.zone_row_i as f32 * self.graphics.tile_height,
+                )
+            });
+        let camera_target = match followed_position {
+            Some(followed_position) => followed_position,
+            None => {
+                self.followed_character_id = None;
+                self.state.player_display.draw_position()
+            }
+        };
+        self.camera.update(camera_target, zone_bounds);
+        let camera_position = self.camera.position();
+
         let screen_width = screen_width();
         let screen_height = screen_height();
-        let zoom_multiplier = self.zoom_mode.camera_factor();
+        // Camera2D zoom is expressed for the whole screen (-1 to 1), so double it
+        let zoom_multiplier = self.camera.zoom() * 2.;
         let zoom_x = (self.state.map.concrete_width / screen_width) * zoom_multiplier;
         let zoom_y = (self.state.map.concrete_height / screen_height) * zoom_multiplier;
         let zoom = Vec2::new(zoom_x, zoom_y);
 
-        let target_x = self.state.player_display.position.x / self.state.map.concrete_width;
+        let target_x = camera_position.x / self.state.map.concrete_width;
         // Invert Y axis because the camera is Y inverted
-        let target_y = -(self.state.player_display.position.y / self.state.map.concrete_height);
+        let target_y = -(camera_position.y / self.state.map.concrete_height);
         let target = Vec2::new(target_x, target_y);
 
-        let tiles_in_width = (screen_width / self.graphics.tile_width) as i32;
-        let tiles_in_height = (screen_height / self.graphics.tile_height) as i32;
-        let player_col_i = (self.state.player_display.position.x / self.graphics.tile_width) as i32;
-        let player_row_i =
-            (self.state.player_display.position.y / self.graphics.tile_height) as i32;
-        let start_area_col_i = player_col_i - tiles_in_width / 2;
-        let start_area_row_i = player_row_i - tiles_in_height / 2;
+        let tiles_in_width =
+            (screen_width / (self.graphics.tile_width * self.camera.zoom())) as i32;
+        let tiles_in_height =
+            (screen_height / (self.graphics.tile_height * self.camera.zoom())) as i32;
+        let center_col_i = (camera_position.x / self.graphics.tile_width) as i32;
+        let center_row_i = (camera_position.y / self.graphics.tile_height) as i32;
+        let start_area_col_i = center_col_i - tiles_in_width / 2;
+        let start_area_row_i = center_row_i - tiles_in_height / 2;
         let displayed_area = (
             (start_area_row_i, start_area_col_i),
             (
-                start_area_row_i + tiles_in_height + 1,
+                start_area_row_i + tiles_in_height + 2,
                 start_area_col_i + tiles_in_width + 1,
             ),
         );
@@ -699,14 +962,10 @@ impl ZoneEngine {
             0.
         };
 
-        let zoom_in = self.zoom_mode == ZoomMode::Double;
+        let zoom_in = self.camera.is_zoomed_in();
         if gui::button::draw_zoom_button(&self.graphics, zoom_in, right_offset) {
             if base_util::mouse_clicked() {
-                match self.zoom_mode {
-                    ZoomMode::Normal => self.user_inputs.push(UserInput::ZoomIn),
-                    ZoomMode::Large => self.user_inputs.push(UserInput::ZoomIn),
-                    ZoomMode::Double => self.user_inputs.push(UserInput::ZoomOut),
-                }
+                self.user_inputs.push(UserInput::SwitchZoom);
                 self.disable_all_user_input_until = get_time() + 0.25;
             }
 
@@ -740,6 +999,96 @@ impl ZoneEngine {
             }
             self.disable_all_user_input = true;
         }
+
+        gui::indicator::draw_transport_mode_indicator(
+            &self.state.player.transport_mode,
+            gui::button::BUTTONS_COLUMN_HEIGHT,
+            right_offset,
+        );
+
+        if gui::indicator::draw_free_look_indicator(
+            self.camera.is_free_look(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchFreeLook);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
+
+        if gui::indicator::draw_journal_indicator(
+            self.journal.is_display(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT * 2.,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchJournal);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
+
+        if gui::indicator::draw_characters_indicator(
+            self.characters_panel.is_display(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT * 3.,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchCharactersPanel);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
+
+        if gui::indicator::draw_stats_indicator(
+            self.stats_history.is_display(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT * 4.,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchStatsPanel);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
+
+        if gui::indicator::draw_need_alerts_indicator(
+            self.need_alerts.is_display_settings(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT * 5.,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchNeedAlertsSettings);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
+
+        if gui::indicator::draw_user_log_history_indicator(
+            self.user_log_history.borrow().is_display(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT * 6.,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchUserLogHistory);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
+
+        if gui::indicator::draw_settings_indicator(
+            self.settings_window.is_display(),
+            gui::button::BUTTONS_COLUMN_HEIGHT + gui::indicator::INDICATOR_HEIGHT * 7.,
+            right_offset,
+        ) {
+            if base_util::mouse_clicked() {
+                self.user_inputs.push(UserInput::SwitchSettingsWindow);
+                self.disable_all_user_input_until = get_time() + 0.25;
+            }
+            self.disable_all_user_input = true;
+        }
     }
 
     fn manage_fresh_socket(&mut self) -> (bool, Vec<message::MainMessage>) {
@@ -758,6 +1107,7 @@ impl ZoneEngine {
 
                 let event = util::request_chat_event();
                 web_socket(&self.state).send_text(&event);
+                self.chat_state.expect_replay();
 
                 if !self.state.player.spritesheet_set {
                     // Request character spritesheet creation
@@ -799,24 +1149,46 @@ impl ZoneEngine {
         &self,
         row_i: usize,
         col_i: usize,
-        walking: &entity::tile::TransportMode,
+        transport_mode: &entity::tile::TransportMode,
     ) -> bool {
-        if let Some(build) = self.state.builds.get(&(row_i as i32, col_i as i32)) {
-            return *build.traversable.get(walking.to_string()).unwrap_or(&true);
+        if let Some(build) = self.state.build_at(row_i as i32, col_i as i32) {
+            return *build
+                .traversable
+                .get(transport_mode.to_string())
+                .unwrap_or(&true);
         }
 
         true
     }
 
+    /// Known characters for chat commands
+    pub fn chat_command_context(&self) -> ChatCommandContext<'_> {
+        ChatCommandContext {
+            player_name: &self.state.player.name,
+            characters: self
+                .state
+                .characters
+                .values()
+                .filter(|character| character.id != self.state.player.id)
+                .map(|character| (character.id.as_str(), character.name.as_str()))
+                .collect(),
+        }
+    }
+
     pub fn zone_position_to_screen_position(&self, row_i: f32, col_i: f32) -> Vec2 {
-        let zoom_factor = self.zoom_mode.factor();
         let absolute_position = Vec2::new(
             col_i * self.graphics.tile_width,
             row_i * self.graphics.tile_height,
         );
+        self.concrete_position_to_screen_position(absolute_position)
+    }
+
+    pub fn concrete_position_to_screen_position(&self, absolute_position: Vec2) -> Vec2 {
+        let zoom_factor = self.camera.zoom();
+        let camera_position = self.camera.position();
         let offset = Vec2::new(
-            self.state.player_display.position.x - (screen_width() / 2.0) / zoom_factor,
-            self.state.player_display.position.y - (screen_height() / 2.0) / zoom_factor,
+            camera_position.x - (screen_width() / 2.0) / zoom_factor,
+            camera_position.y - (screen_height() / 2.0) / zoom_factor,
         );
         let relative_position = absolute_position - offset;
         let relative_position = relative_position * zoom_factor;
@@ -853,12 +1225,19 @@ impl Engine for ZoneEngine {
         self.proceed_quick_action_requests();
         messages.extend(self.proceed_description_requests());
         self.proceed_inventory_requests();
+        self.proceed_player_character_request();
+        self.proceed_zone_characters_request();
+        self.proceed_build_name_request();
+        self.proceed_ground_stack_requests();
+        self.chat_state.save_history(false);
+        self.stats_history.save(false);
+        self.context_menu.proceed_requests();
         messages.extend(self.recv_events());
         let draw_area = self.camera();
 
         // Game
+        self.update_camera_animations();
         self.scene(draw_area);
-        self.camera_animations();
         self.draw_zone_ux();
         let action_clicked = self.draw_current_action();
         self.draw_request_clicks();
@@ -868,6 +1247,8 @@ impl Engine for ZoneEngine {
         self.ui_animations();
         self.draw_zone_debug(draw_area);
         self.draw_characters_names(draw_area);
+        self.draw_speech_bubbles();
+        self.draw_construction_tooltip();
         self.disable_all_user_input = false;
         messages.extend(self.draw_left_panel());
         self.draw_resume_items();
@@ -888,20 +1269,6 @@ impl Engine for ZoneEngine {
 
         messages
     }
-
-    fn signal_illustration_loaded(&mut self, _illustration_name: &str) {
-        if let Some(current_description) = self.current_description.as_mut() {
-            // Faking is_first_frame will permit to rerun .check_init description function
-            current_description.is_first_frame = true;
-        }
-    }
-
-    fn replace_graphics(&mut self, graphics: crate::graphics::Graphics) {
-        self.graphics = graphics.clone();
-        if let Some(current_description) = self.current_description.as_mut() {
-            current_description.graphics = graphics;
-        }
-    }
 }
 
 pub enum PlayerRunning {
@@ -913,41 +1280,31 @@ pub enum PlayerRunning {
 
 pub enum UserInput {
     MovePlayerBy(Vec2),
-    ZoomIn,
-    ZoomOut,
+    ZoomBy(f32),
+    SwitchZoom,
+    MoveCameraBy(Vec2),
+    SwitchFreeLook,
+    // row_i, col_i
+    CenterCameraOn(i32, i32),
+    SwitchJournal,
+    SwitchCharactersPanel,
+    SwitchStatsPanel,
+    SwitchNeedAlertsSettings,
+    SwitchUserLogHistory,
+    SwitchSettingsWindow,
+    FollowCharacter(Option<String>),
+    // build id
+    OpenBuildDescription(i32),
+    // row_i, col_i
+    OpenGroundStack(i32, i32),
+    // row_i, col_i, screen position
+    OpenContextMenu(i32, i32, Vec2),
     SwitchRunningMode,
     InRunningMode,
     InWalkingMode,
     SubmitChatInput,
 }
 
-#[derive(PartialEq)]
-pub enum ZoomMode {
-    Normal,
-    Double,
-    Large,
-}
-
-impl ZoomMode {
-    pub fn camera_factor(&self) -> f32 {
-        match self {
-            ZoomMode::Large => 1.,
-            ZoomMode::Normal => 2.,
-            ZoomMode::Double => {
-                if is_mobile() {
-                    6.
-                } else {
-                    4.
-                }
-            }
-        }
-    }
-
-    pub fn factor(&self) -> f32 {
-        self.camera_factor() / 2.0
-    }
-}
-
 pub enum UserEvent {
     // zone_row_i, zone_col_i, post_base_url
     InventoryItemDropped(usize, usize, String),
@@ -0,0 +1,212 @@
+use macroquad::prelude::*;
+
+use super::{util, web_socket, PlayerRunning, ZoneEngine};
+
+/// Duration (seconds) of a player movement simulation step. Velocities are expressed in concrete
+/// pixels per step, so movement speed doesn't depend on frame rate
+pub const MOVEMENT_STEP: f64 = 1. / 60.;
+// Avoid to simulate a long catch up after a freeze (application in background, etc.)
+const MAX_STEPS_PER_FRAME: u32 = 15;
+const DEFAULT_PLAYER_VELOCITY_DIVIDER: f32 = 2.5;
+const DEFAULT_PLAYER_VELOCITY_LIMIT: f32 = 2.0;
+const RUNNING_PLAYER_VELOCITY_LIMIT: f32 = 5.0;
+
+/// Accumulate frame time and give the movement steps to simulate
+#[derive(Default)]
+pub struct FixedTimestep {
+    accumulator: f64,
+}
+
+impl FixedTimestep {
+    /// Add elapsed frame time and return the steps count to simulate
+    pub fn advance(&mut self, frame_time: f64) -> u32 {
+        self.accumulator += frame_time;
+        let steps = (self.accumulator / MOVEMENT_STEP) as u32;
+        self.accumulator -= steps as f64 * MOVEMENT_STEP;
+
+        if steps > MAX_STEPS_PER_FRAME {
+            self.accumulator = 0.;
+            return MAX_STEPS_PER_FRAME;
+        }
+
+        steps
+    }
+
+    /// Progression (0.0 to 1.0) between last simulated step and the next one
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / MOVEMENT_STEP) as f32
+    }
+}
+
+pub fn velocity_limit(running: bool) -> f32 {
+    if running {
+        RUNNING_PLAYER_VELOCITY_LIMIT
+    } else {
+        DEFAULT_PLAYER_VELOCITY_LIMIT
+    }
+}
+
+/// Player velocity after one movement step. Input is the wanted acceleration and speed the tile
+/// hump speed
+pub fn step_velocity(velocity: Vec2, input: Vec2, velocity_limit: f32, speed: f32) -> Vec2 {
+    let mut velocity = velocity + input - velocity / DEFAULT_PLAYER_VELOCITY_DIVIDER;
+    if velocity.length() > velocity_limit {
+        velocity = velocity.normalize() * velocity_limit;
+    }
+
+    velocity * speed
+}
+
+impl ZoneEngine {
+    /// Simulate player movement steps matching elapsed frame time
+    pub fn update_player_movement(&mut self, input: Vec2) {
+        let steps = self.movement_timestep.advance(get_frame_time() as f64);
+        for _ in 0..steps {
+            self.step_player_movement(input);
+        }
+        self.state.player_display.interpolation = self.movement_timestep.alpha();
+
+        // Update player running animation
+        let velocity = self.state.player_display.velocity;
+        let was_running = self.state.player_display.running.is_some();
+        let mut player_running: Option<PlayerRunning> = None;
+        if velocity.length() > 0.05 {
+            player_running = if velocity.y < -0.05 {
+                Some(PlayerRunning::Top)
+            } else if velocity.y > 0.05 {
+                Some(PlayerRunning::Down)
+            } else if velocity.x > 0.05 {
+                Some(PlayerRunning::Right)
+            } else if velocity.x < -0.05 {
+                Some(PlayerRunning::Left)
+            } else {
+                None
+            };
+        }
+
+        if was_running && player_running.is_none() {
+            let coordinates = (self.state.player.zone_row_i, self.state.player.zone_col_i);
+            if coordinates != self.last_require_around_coordinate {
+                let event = util::require_around_event(&self.state);
+                web_socket(&self.state).send_text(&event);
+                self.last_require_around_coordinate = coordinates;
+            }
+        }
+
+        self.state.player_display.running = player_running;
+    }
+
+    fn step_player_movement(&mut self, input: Vec2) {
+        let transport_mode = self.state.player.transport_mode.clone();
+        let speed = self.state.map.get_speed(
+            self.state.player.zone_row_i as usize,
+            self.state.player.zone_col_i as usize,
+            &transport_mode,
+        );
+        self.state.player_display.velocity = step_velocity(
+            self.state.player_display.velocity,
+            input,
+            velocity_limit(self.running_mode),
+            speed,
+        );
+
+        // Update player position according to its velocity
+        self.state.player_display.previous_position = self.state.player_display.position;
+        let next_position = self.state.player_display.position + self.state.player_display.velocity;
+
+        // Update player zone coordinates if changed
+        let half_size_width = self.graphics.tile_width / 2.;
+        let half_size_height = self.graphics.tile_height / 2.;
+        let next_player_center_x = next_position.x + half_size_width;
+        let next_player_center_y = next_position.y + half_size_height;
+
+        let next_player_row_i = (next_player_center_y / self.graphics.tile_height) as i32;
+        let next_player_col_i = (next_player_center_x / self.graphics.tile_width) as i32;
+
+        let tile_is_traversable = self.state.map.traversable(
+            next_player_row_i as usize,
+            next_player_col_i as usize,
+            &transport_mode,
+        );
+        let possible_build_is_traversable = self.possible_build_is_traversable(
+            next_player_row_i as usize,
+            next_player_col_i as usize,
+            &transport_mode,
+        );
+        if tile_is_traversable && possible_build_is_traversable {
+            self.state.player_display.position = next_position;
+            if next_player_row_i != self.state.player.zone_row_i
+                || next_player_col_i != self.state.player.zone_col_i
+            {
+                self.state.player.zone_row_i = next_player_row_i;
+                self.state.player.zone_col_i = next_player_col_i;
+                let player_move_event = util::player_move_event(&self.state);
+                web_socket(&self.state).send_text(&player_move_event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::tile::HumpType;
+
+    // Hold right direction during one second and return covered distance, as drawn
+    fn distance_in_one_second(frame_time: f64, hump_type: &HumpType) -> f32 {
+        let mut timestep = FixedTimestep::default();
+        let mut previous_position = Vec2::ZERO;
+        let mut position = Vec2::ZERO;
+        let mut velocity = Vec2::ZERO;
+        let limit = velocity_limit(false);
+
+        let frames = (1. / frame_time).round() as usize;
+        for _ in 0..frames {
+            for _ in 0..timestep.advance(frame_time) {
+                velocity = step_velocity(velocity, Vec2::X, limit, hump_type.to_speed());
+                previous_position = position;
+                position += velocity;
+            }
+        }
+
+        previous_position.lerp(position, timestep.alpha()).x
+    }
+
+    #[test]
+    fn distance_per_second_is_independent_of_frame_time() {
+        for hump_type in [HumpType::Normal, HumpType::Slow, HumpType::VerySlow] {
+            let reference = distance_in_one_second(1. / 60., &hump_type);
+            assert!(reference > 0.);
+
+            for frame_time in [1. / 7., 1. / 30., 1. / 144., 1. / 240.] {
+                let distance = distance_in_one_second(frame_time, &hump_type);
+                assert!(
+                    (distance - reference).abs() < 0.01,
+                    "{:?} at {}s per frame : {} instead of {}",
+                    hump_type,
+                    frame_time,
+                    distance,
+                    reference,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn slower_hump_covers_less_distance() {
+        let normal = distance_in_one_second(1. / 60., &HumpType::Normal);
+        let slow = distance_in_one_second(1. / 60., &HumpType::Slow);
+        let very_slow = distance_in_one_second(1. / 60., &HumpType::VerySlow);
+
+        assert!(normal > slow);
+        assert!(slow > very_slow);
+    }
+
+    #[test]
+    fn long_freeze_is_not_caught_up() {
+        let mut timestep = FixedTimestep::default();
+
+        assert_eq!(timestep.advance(5.), MAX_STEPS_PER_FRAME);
+        assert_eq!(timestep.alpha(), 0.);
+    }
+}
@@ -0,0 +1,83 @@
+use egui::Context;
+
+use crate::{i18n, settings::Settings, ui::language::language_selector};
+
+const SETTINGS_WINDOW_WIDTH: f32 = 300.;
+
+/// Window to edit and save user settings
+#[derive(Default)]
+pub struct SettingsWindow {
+    display: bool,
+    mouse_hover: bool,
+}
+
+impl SettingsWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_display(&self) -> bool {
+        self.display
+    }
+
+    pub fn set_display(&mut self, display: bool) {
+        self.display = display;
+        if !display {
+            self.mouse_hover = false;
+        }
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    /// Draw settings window and return true if settings changed (they are then saved)
+    pub fn ui(&mut self, egui_ctx: &Context, settings: &mut Settings) -> bool {
+        let mut display = self.display;
+        let mut changed = false;
+
+        let response = egui::Window::new(i18n::tr("settings.title"))
+            .open(&mut display)
+            .default_width(SETTINGS_WINDOW_WIDTH)
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    changed |= language_selector(ui, &mut settings.language);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("settings.min_zoom"));
+                    changed |= ui
+                        .add(egui::Slider::new(&mut settings.camera_min_zoom, 0.25..=1.0))
+                        .changed();
+                });
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("settings.max_zoom"));
+                    changed |= ui
+                        .add(egui::Slider::new(&mut settings.camera_max_zoom, 1.0..=6.0))
+                        .changed();
+                });
+                changed |= ui
+                    .checkbox(
+                        &mut settings.speech_bubbles,
+                        i18n::tr("settings.speech_bubbles"),
+                    )
+                    .changed();
+                changed |= ui
+                    .checkbox(
+                        &mut settings.terrain_transitions,
+                        i18n::tr("settings.terrain_transitions"),
+                    )
+                    .changed();
+            });
+
+        if changed {
+            settings.save();
+        }
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        self.set_display(display);
+
+        changed
+    }
+}
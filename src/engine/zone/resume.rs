@@ -1,9 +1,22 @@
-use crate::event::model::ItemModel;
+use crate::{event::model::ItemModel, i18n};
 use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::gui::{blink::BlinkingIcon, component::ProgressBar, resume::ResumeItem};
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// Resume item key, from its stable key or (when absent) from its label in any language
+fn item_key(item: &ItemModel) -> Option<&'static str> {
+    match &item.key {
+        Some(key) => i18n::key_from_text("resume.", key),
+        None => i18n::key_from_text("resume.", &item.name),
+    }
+}
+
+fn value_key(value: &str) -> Option<&'static str> {
+    i18n::key_from_text("resume.value.", value)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub enum Health {
     Ok,
     Middle,
@@ -14,11 +27,11 @@ pub enum Health {
 impl Health {
     pub fn from_item(item: &ItemModel) -> Result<Self, String> {
         if let Some(value) = &item.value_str {
-            match value.as_str() {
-                "Ok" => return Ok(Self::Ok),
-                "Moyen" => return Ok(Self::Middle),
-                "Mauvais" => return Ok(Self::Bad),
-                "Critique" => return Ok(Self::Critical),
+            match value_key(value) {
+                Some("resume.value.ok") => return Ok(Self::Ok),
+                Some("resume.value.middle") => return Ok(Self::Middle),
+                Some("resume.value.bad") => return Ok(Self::Bad),
+                Some("resume.value.critical") => return Ok(Self::Critical),
                 _ => return Err(format!("Unable to understand Health name ! '{}'", value)),
             }
         };
@@ -26,6 +39,25 @@ impl Health {
         Err("Unable to understand Health : no value".to_string())
     }
 
+    pub fn name(&self) -> &str {
+        match self {
+            Health::Ok => i18n::tr("resume.value.ok"),
+            Health::Middle => i18n::tr("resume.value.middle"),
+            Health::Bad => i18n::tr("resume.value.bad"),
+            Health::Critical => i18n::tr("resume.value.critical"),
+        }
+    }
+
+    /// Higher is worse
+    pub fn severity(&self) -> u8 {
+        match self {
+            Health::Ok => 0,
+            Health::Middle => 1,
+            Health::Bad => 2,
+            Health::Critical => 3,
+        }
+    }
+
     pub fn item(&self) -> ResumeItem {
         match self {
             Health::Ok => ResumeItem::GoodSmiley,
@@ -46,10 +78,10 @@ pub enum CanEat {
 impl CanEat {
     pub fn from_item(item: &ItemModel) -> Result<Self, String> {
         if let Some(value_str) = &item.value_str {
-            match value_str.as_str() {
-                "Oui" => Ok(Self::Yes),
-                "Non" => Ok(Self::No),
-                "Faible" => Ok(Self::Lower),
+            match value_key(value_str) {
+                Some("resume.value.yes") => Ok(Self::Yes),
+                Some("resume.value.no") => Ok(Self::No),
+                Some("resume.value.low") => Ok(Self::Lower),
                 _ => {
                     return Err(format!(
                         "Unable to understand A manger value : '{}'",
@@ -81,10 +113,10 @@ pub enum CanDrink {
 impl CanDrink {
     pub fn from_item(item: &ItemModel) -> Result<Self, String> {
         if let Some(value_str) = &item.value_str {
-            match value_str.as_str() {
-                "Oui" => Ok(Self::Yes),
-                "Non" => Ok(Self::No),
-                "Faible" => Ok(Self::Lower),
+            match value_key(value_str) {
+                Some("resume.value.yes") => Ok(Self::Yes),
+                Some("resume.value.no") => Ok(Self::No),
+                Some("resume.value.low") => Ok(Self::Lower),
                 _ => {
                     return Err(format!(
                         "Unable to understand A boire value : '{}'",
@@ -106,7 +138,7 @@ impl CanDrink {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct CharacterResume {
     pub health: Health,
     pub action_points: f32,
@@ -136,59 +168,59 @@ impl CharacterResume {
         let mut messages: Option<i32> = None;
 
         for item in &resume_texts {
-            match &item.name.as_str() {
-                &"PV" => {
+            match item_key(item) {
+                Some("resume.health") => {
                     health = Some(Health::from_item(item)?);
                 }
-                &"PA" => {
+                Some("resume.action_points") => {
                     if let Some(value_float) = item.value_float {
                         action_points = Some(value_float);
                     } else {
                         return Err("Unable to understand PA : no value".to_string());
                     }
                 }
-                &"Faim" => {
+                Some("resume.hunger") => {
                     hungry = Some(
                         ProgressBar::from_item(item)
                             .or_else(|e| Err(format!("Unable to understand Faim : '{}'", e)))?,
                     );
                 }
-                &"Soif" => {
+                Some("resume.thirst") => {
                     thirsty = Some(
                         ProgressBar::from_item(item)
                             .or_else(|e| Err(format!("Unable to understand Soif : '{}'", e)))?,
                     );
                 }
-                &"Fatigue" => {
+                Some("resume.tiredness") => {
                     tiredness = Some(
                         ProgressBar::from_item(item)
                             .or_else(|e| Err(format!("Unable to understand Fatigue : '{}'", e)))?,
                     );
                 }
-                &"A boire" => can_drink = Some(CanDrink::from_item(item)?),
-                &"A manger" => can_eat = Some(CanEat::from_item(item)?),
-                &"Suivis" => {
+                Some("resume.can_drink") => can_drink = Some(CanDrink::from_item(item)?),
+                Some("resume.can_eat") => can_eat = Some(CanEat::from_item(item)?),
+                Some("resume.follow") => {
                     if let Some(value) = item.value_float {
                         follow = Some(value as i32)
                     } else {
                         return Err("Unable to understand Suivis : no value".to_string());
                     }
                 }
-                &"Suiveurs" => {
+                Some("resume.followers") => {
                     if let Some(value) = item.value_float {
                         follower = Some(value as i32)
                     } else {
                         return Err("Unable to understand Suivis : no value".to_string());
                     }
                 }
-                &"Combattants" => {
+                Some("resume.fighters") => {
                     if let Some(value) = item.value_float {
                         fighters = Some(value as i32)
                     } else {
                         return Err("Unable to understand Combattants : no value".to_string());
                     }
                 }
-                &"Messages" => {
+                Some("resume.messages") => {
                     if let Some(value) = item.value_float {
                         messages = Some(value as i32)
                     } else {
@@ -282,6 +314,32 @@ impl CharacterResume {
         return Err("No PV resume found".to_string());
     }
 
+    /// Texts (and their color) telling what changed : action points spent or won, health
+    pub fn changes_from_compare(&self, after: &CharacterResume) -> Vec<(String, Color)> {
+        let mut changes = vec![];
+
+        // Round to avoid float noise like "-0.99999"
+        let action_points = ((after.action_points - self.action_points) * 10.).round() / 10.;
+        if action_points < 0. {
+            changes.push((
+                format!("{} {}", action_points, i18n::tr("resume.action_points")),
+                ORANGE,
+            ));
+        } else if action_points > 0. {
+            changes.push((
+                format!("+{} {}", action_points, i18n::tr("resume.action_points")),
+                GREEN,
+            ));
+        }
+        if after.health.severity() > self.health.severity() {
+            changes.push((after.health.name().to_string(), RED));
+        } else if after.health.severity() < self.health.severity() {
+            changes.push((after.health.name().to_string(), GREEN));
+        }
+
+        changes
+    }
+
     pub fn icons_from_compare(&self, after: &CharacterResume) -> Vec<BlinkingIcon> {
         let mut resume_items = vec![];
 
@@ -325,3 +383,108 @@ impl CharacterResume {
         blinking_icons
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float_item(name: &str, key: Option<&str>, value: f32) -> ItemModel {
+        ItemModel {
+            name: name.to_string(),
+            key: key.map(|key| key.to_string()),
+            value_is_str: false,
+            value_is_float: true,
+            value_str: None,
+            value_float: Some(value),
+            url: None,
+            // Needed by progress bars items
+            classes: vec!["green".to_string()],
+        }
+    }
+
+    fn str_item(name: &str, key: Option<&str>, value: &str) -> ItemModel {
+        ItemModel {
+            name: name.to_string(),
+            key: key.map(|key| key.to_string()),
+            value_is_str: true,
+            value_is_float: false,
+            value_str: Some(value.to_string()),
+            value_float: None,
+            url: None,
+            classes: vec![],
+        }
+    }
+
+    fn french_resume_texts() -> Vec<ItemModel> {
+        vec![
+            str_item("PV", None, "Moyen"),
+            float_item("PA", None, 12.5),
+            float_item("Faim", None, 40.),
+            float_item("Soif", None, 60.),
+            float_item("Fatigue", None, 80.),
+            str_item("A boire", None, "Oui"),
+            str_item("A manger", None, "Faible"),
+            float_item("Suivis", None, 1.),
+            float_item("Suiveurs", None, 2.),
+            float_item("Combattants", None, 3.),
+            float_item("Messages", None, 4.),
+        ]
+    }
+
+    #[test]
+    fn from_resume_texts_with_french_names() {
+        let resume = CharacterResume::from_resume_texts(french_resume_texts()).unwrap();
+
+        assert_eq!(resume.health, Health::Middle);
+        assert_eq!(resume.action_points, 12.5);
+        assert_eq!(resume.can_drink, CanDrink::Yes);
+        assert_eq!(resume.can_eat, CanEat::Lower);
+        assert_eq!(resume.follow, 1);
+        assert_eq!(resume.follower, 2);
+        assert_eq!(resume.fighters, 3);
+        assert_eq!(resume.messages, 4);
+    }
+
+    #[test]
+    fn from_resume_texts_with_keys_and_english_values() {
+        let resume_texts = vec![
+            str_item("Health", Some("health"), "Critical"),
+            float_item("Action points", Some("action_points"), 3.),
+            float_item("Hunger", Some("hunger"), 40.),
+            float_item("Thirst", Some("thirst"), 60.),
+            float_item("Tiredness", Some("tiredness"), 80.),
+            str_item("Can drink", Some("can_drink"), "No"),
+            str_item("Can eat", Some("can_eat"), "yes"),
+            float_item("Followed", Some("follow"), 0.),
+            float_item("Followers", Some("followers"), 0.),
+            float_item("Fighters", Some("fighters"), 0.),
+            float_item("Messages", Some("messages"), 0.),
+        ];
+
+        let resume = CharacterResume::from_resume_texts(resume_texts).unwrap();
+
+        assert_eq!(resume.health, Health::Critical);
+        assert_eq!(resume.action_points, 3.);
+        assert_eq!(resume.can_drink, CanDrink::No);
+        assert_eq!(resume.can_eat, CanEat::Yes);
+    }
+
+    #[test]
+    fn from_resume_texts_fails_on_missing_item() {
+        let mut resume_texts = french_resume_texts();
+        resume_texts.retain(|item| item.name != "PA");
+
+        assert_eq!(
+            CharacterResume::from_resume_texts(resume_texts).unwrap_err(),
+            "No AP resume found"
+        );
+    }
+
+    #[test]
+    fn from_resume_texts_fails_on_unknown_health() {
+        let mut resume_texts = french_resume_texts();
+        resume_texts[0] = str_item("PV", None, "Excellent");
+
+        assert!(CharacterResume::from_resume_texts(resume_texts).is_err());
+    }
+}
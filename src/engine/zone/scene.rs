@@ -1,191 +1,239 @@
-use crate::{engine::zone::state, graphics, util::get_text_center};
+use crate::util::get_text_center;
 
 use macroquad::prelude::*;
 
-use super::{debug::DebugInfo, util::in_area, ZoneEngine};
+use super::{
+    debug::DebugInfo,
+    layer::{Drawable, RenderLayer, RenderQueue},
+    ZoneEngine,
+};
+
+const UNDER_CONSTRUCTION_TILE_ID: &str = "LITTLE_SHOVEL";
+const UNDER_CONSTRUCTION_GHOST_COLOR: Color = Color::new(1.0, 1.0, 1.0, 0.45);
+// Maximum drawn items of a ground stack, and the shift (logical pixels) between them
+const STACK_DRAWN_ITEMS: usize = 3;
+const STACK_ITEM_SHIFT: f32 = 3.;
 
 impl ZoneEngine {
     pub fn scene(&mut self, draw_area: ((i32, i32), (i32, i32))) {
         let mut display_counter = DebugInfo::new();
         let map = &self.state.map;
         let tiles = &self.state.map.tiles;
-        let player_display = &self.state.player_display;
+        let tile_width = self.graphics.tile_width;
+        let tile_height = self.graphics.tile_height;
+        let mut queue = RenderQueue::new(
+            map.concrete_width,
+            map.concrete_height,
+            self.tick_i,
+            self.tick9_i,
+        );
 
-        // Draw zone tiles
-        for (row_i, row) in tiles.iter().enumerate() {
-            for (col_i, tile_id) in row.iter().enumerate() {
-                if tile_id == "UNKNOWN"
-                    || tile_id == "NOTHING"
-                    || !in_area(row_i as i32, col_i as i32, &draw_area)
-                {
+        // Zone tiles
+        let ((row_min, col_min), (row_max, col_max)) = draw_area;
+        for row_i in row_min.max(0)..=row_max.min(tiles.len() as i32 - 1) {
+            let row = &tiles[row_i as usize];
+            for col_i in col_min.max(0)..=col_max.min(row.len() as i32 - 1) {
+                let tile_id = &row[col_i as usize];
+                if tile_id == "UNKNOWN" || tile_id == "NOTHING" {
                     continue;
                 }
 
                 // Tile destination in the concrete display
-                let dest_x = col_i as f32 * self.graphics.tile_width;
-                let dest_y = row_i as f32 * self.graphics.tile_height;
+                let dest_x = col_i as f32 * tile_width;
+                let dest_y = row_i as f32 * tile_height;
 
                 display_counter.incr_zone_tile_count();
-                self.graphics.draw_tile_in_camera(
-                    map.concrete_width,
-                    map.concrete_height,
-                    dest_x,
+                queue.push(
+                    RenderLayer::Ground,
                     dest_y,
-                    tile_id,
-                    Some(&self.state.map.background_tile_id),
-                    self.tick_i,
-                    None,
-                    None,
+                    Drawable::Tile {
+                        tile_id,
+                        background_tile_id: Some(&map.background_tile_id),
+                        dest_x,
+                        dest_y,
+                    },
                 );
+
+                if self.settings.terrain_transitions {
+                    for overlay_tile_id in self.state.autotiles.overlays(row_i, col_i) {
+                        queue.push(
+                            RenderLayer::Ground,
+                            dest_y,
+                            Drawable::Tile {
+                                tile_id: overlay_tile_id,
+                                background_tile_id: None,
+                                dest_x,
+                                dest_y,
+                            },
+                        );
+                    }
+                }
             }
         }
 
-        // Draw builds tiles
-        for (_, build) in &self.state.builds {
-            let dest_x = build.col_i as f32 * self.graphics.tile_width;
-            let dest_y = build.row_i as f32 * self.graphics.tile_height;
-
-            if !in_area(build.row_i, build.col_i, &draw_area) {
-                continue;
+        for (row_i, col_i, tile_entities) in self.state.index.in_area(&draw_area) {
+            let dest_x = col_i as f32 * tile_width;
+            let dest_y = row_i as f32 * tile_height;
+
+            // Builds : floors are under everything, others stand with characters
+            if let Some(indexed_build) = &tile_entities.build {
+                let build = self.state.builds.get(&indexed_build.id);
+                let layer = match build {
+                    Some(build) if build.is_floor => RenderLayer::FloorBuilds,
+                    _ => RenderLayer::Actors,
+                };
+
+                display_counter.incr_build_count();
+                if build.map(|build| build.under_construction).unwrap_or(false) {
+                    // Transparent ghost of the future build, with scaffold over it
+                    queue.push(
+                        layer,
+                        dest_y,
+                        Drawable::ColoredTile {
+                            tile_id: &indexed_build.tile_id,
+                            color: UNDER_CONSTRUCTION_GHOST_COLOR,
+                            dest_x,
+                            dest_y,
+                        },
+                    );
+                    queue.push(
+                        layer,
+                        dest_y,
+                        Drawable::Tile {
+                            tile_id: UNDER_CONSTRUCTION_TILE_ID,
+                            background_tile_id: None,
+                            dest_x,
+                            dest_y,
+                        },
+                    );
+                } else {
+                    queue.push(
+                        layer,
+                        dest_y,
+                        Drawable::Tile {
+                            tile_id: &indexed_build.tile_id,
+                            background_tile_id: None,
+                            dest_x,
+                            dest_y,
+                        },
+                    );
+                }
             }
 
-            // TODO : optimize by compute each stuff_id / tile_id a zone creation
-            let tile_id = self.graphics.find_tile_id_from_classes(&build.classes);
-            display_counter.incr_build_count();
-            self.graphics.draw_tile_in_camera(
-                map.concrete_width,
-                map.concrete_height,
-                dest_x,
-                dest_y,
-                &tile_id,
-                None,
-                self.tick_i,
-                None,
-                None,
-            );
-
-            if build.under_construction {
-                self.graphics.draw_tile_in_camera(
-                    map.concrete_width,
-                    map.concrete_height,
-                    dest_x,
-                    dest_y,
-                    "LITTLE_SHOVEL",
-                    None,
-                    self.tick_i,
-                    None,
-                    None,
+            // Ground items : only stack top items are drawn (shifted), with the stack count
+            let items_count = tile_entities.resources.len() + tile_entities.stuffs.len();
+            let first_drawn_i = items_count.saturating_sub(STACK_DRAWN_ITEMS);
+            let items = tile_entities
+                .resources
+                .iter()
+                .map(|resource| (&resource.tile_id, false))
+                .chain(
+                    tile_entities
+                        .stuffs
+                        .iter()
+                        .map(|stuff| (&stuff.tile_id, true)),
                 );
-            }
-        }
-
-        // Draw resource tiles
-        for resources in self.state.resources.values() {
-            for resource in resources {
-                if !in_area(resource.zone_row_i, resource.zone_col_i, &draw_area) {
+            for (i, (tile_id, is_stuff)) in items.enumerate() {
+                if is_stuff {
+                    display_counter.incr_stuff_count();
+                } else {
+                    display_counter.incr_resource_count();
+                }
+                if i < first_drawn_i {
                     continue;
                 }
 
-                let dest_x = resource.zone_col_i as f32 * self.graphics.tile_width;
-                let dest_y = resource.zone_row_i as f32 * self.graphics.tile_height;
-
-                display_counter.incr_resource_count();
-                self.graphics.draw_tile_in_camera(
-                    map.concrete_width,
-                    map.concrete_height,
-                    dest_x,
+                let shift = (i - first_drawn_i) as f32 * STACK_ITEM_SHIFT;
+                queue.push(
+                    RenderLayer::GroundItems,
                     dest_y,
-                    &resource.id,
-                    None,
-                    self.tick_i,
-                    None,
-                    None,
+                    Drawable::Tile {
+                        tile_id,
+                        background_tile_id: None,
+                        dest_x: dest_x + shift,
+                        dest_y: dest_y - shift,
+                    },
                 );
             }
-        }
-
-        // Draw stuff tiles
-        for (_, stuff) in &self.state.stuffs {
-            if !in_area(stuff.zone_row_i, stuff.zone_col_i, &draw_area) {
-                continue;
+            if items_count > 1 {
+                queue.push(
+                    RenderLayer::GroundItems,
+                    dest_y,
+                    Drawable::CountBadge {
+                        count: items_count as u32,
+                        dest_x,
+                        dest_y,
+                    },
+                );
             }
 
-            let dest_x = stuff.zone_col_i as f32 * self.graphics.tile_width;
-            let dest_y = stuff.zone_row_i as f32 * self.graphics.tile_height;
-
-            // TODO : optimize by compute each stuff_id / tile_id a zone creation
-            // TODO : clone here is bad performance
-            let classes = vec![stuff.classes.clone(), vec![stuff.stuff_id.clone()]]
-                .into_iter()
-                .flatten()
-                .collect();
-
-            let tile_id = self.graphics.find_tile_id_from_classes(&classes);
-            display_counter.incr_stuff_count();
-            self.graphics.draw_tile_in_camera(
-                map.concrete_width,
-                map.concrete_height,
-                dest_x,
-                dest_y,
-                &tile_id,
-                None,
-                self.tick_i,
-                None,
-                None,
-            );
-        }
+            for character_id in &tile_entities.character_ids {
+                if character_id == &self.state.player.id {
+                    continue;
+                }
 
-        for character in self.state.characters.values() {
-            if character.id == self.state.player.id
-                || !in_area(character.zone_row_i, character.zone_col_i, &draw_area)
-            {
-                continue;
+                display_counter.incr_character_count();
+                queue.push(
+                    RenderLayer::Actors,
+                    dest_y,
+                    Drawable::Character {
+                        character_id,
+                        running: &None,
+                        dest_x,
+                        dest_y,
+                    },
+                );
             }
-
-            let dest_x = character.zone_col_i as f32 * self.graphics.tile_width;
-            let dest_y = character.zone_row_i as f32 * self.graphics.tile_height;
-
-            display_counter.incr_character_count();
-            self.graphics.draw_character_in_camera(
-                map.concrete_width,
-                map.concrete_height,
-                dest_x,
-                dest_y,
-                self.tick9_i,
-                &character.id,
-                &None,
-            );
         }
 
-        // Draw player
-        self.graphics.draw_character_in_camera(
-            map.concrete_width,
-            map.concrete_height,
-            self.state.player_display.position.x,
-            self.state.player_display.position.y,
-            self.tick9_i,
-            &self.state.player.id,
-            &self.state.player_display.running,
+        // Player (its display position is between tiles when moving)
+        let player_display = &self.state.player_display;
+        let player_position = player_display.draw_position();
+        queue.push(
+            RenderLayer::Actors,
+            player_position.y,
+            Drawable::Character {
+                character_id: &self.state.player.id,
+                running: &player_display.running,
+                dest_x: player_position.x,
+                dest_y: player_position.y,
+            },
         );
 
+        self.push_overlays(&mut queue);
+        queue.draw(&self.graphics);
+
         if self.frame_i % 30 == 0 {
             self.debug_info = display_counter;
             self.debug_info.set_fps(get_fps());
         }
     }
 
+    /// Register camera drawn overlays (animations, ...) into the scene
+    fn push_overlays<'a>(&'a self, queue: &mut RenderQueue<'a>) {
+        for animation in &self.camera_animations {
+            queue.push(
+                RenderLayer::Overlays,
+                0.,
+                Drawable::Custom(Box::new(move |graphics| animation.draw_in_camera(graphics))),
+            );
+        }
+    }
+
     pub fn draw_characters_names(&self, draw_area: ((i32, i32), (i32, i32))) {
-        let zoom_factor = self.zoom_mode.factor();
+        let zoom_factor = self.camera.zoom();
         let half_tile_width = (self.graphics.tile_width * zoom_factor) / 2.0;
         let tile_height = self.graphics.tile_width * zoom_factor;
         let font_size: u16 = 24;
         let font_scale = 1.0;
 
-        for character in self.state.characters.values() {
-            if !in_area(character.zone_row_i, character.zone_col_i, &draw_area) {
-                continue;
-            }
-
+        for character in self
+            .state
+            .index
+            .in_area(&draw_area)
+            .flat_map(|(_, _, tile_entities)| tile_entities.character_ids.iter())
+            .filter_map(|character_id| self.state.characters.get(character_id))
+        {
             let text_center = get_text_center(&character.name, None, font_size, font_scale, 0.);
             let dest_row_i = character.zone_row_i as f32;
             let dest_col_i = character.zone_col_i as f32;
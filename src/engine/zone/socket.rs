@@ -1,7 +1,7 @@
 use macroquad::prelude::*;
 use quad_net::web_socket::WebSocket;
 
-use crate::SERVER_ADDRESS;
+use crate::{i18n, SERVER_ADDRESS};
 
 pub fn get_socket(state: &super::state::ZoneState) -> Result<WebSocket, String> {
     let ws_url = get_url(state);
@@ -9,7 +9,7 @@ pub fn get_socket(state: &super::state::ZoneState) -> Result<WebSocket, String>
 
     match WebSocket::connect(&ws_url) {
         Ok(socket_) => Ok(socket_),
-        Err(error) => Err(format!("Erreur de connexion web socket : {:?}", error)),
+        Err(error) => Err(format!("{} : {:?}", i18n::tr("web_socket_error"), error)),
     }
 }
 
@@ -0,0 +1,214 @@
+use ahash::AHashMap;
+use egui::Context;
+use macroquad::prelude::*;
+use quad_net::http_request::Request;
+
+use crate::{client::Client, entity::description::Description, i18n};
+
+use super::{state::ZoneState, util};
+
+const STACK_WINDOW_WIDTH: f32 = 250.;
+
+pub enum GroundStackEvent {
+    // Url of the chosen pick up action
+    PickUp(String),
+}
+
+/// Ground item, by its id (resources are identified by their tile too)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum GroundItem {
+    Stuff(i32),
+    Resource(String),
+}
+
+/// Name and actions given by server for one ground item
+struct GroundItemLook {
+    name: String,
+    // Action label and url
+    actions: Vec<(String, String)>,
+}
+
+/// Window listing items lying on a tile, with pick up actions
+#[derive(Default)]
+pub struct GroundStack {
+    // row_i, col_i of displayed stack
+    tile: Option<(i32, i32)>,
+    mouse_hover: bool,
+    // Looks of displayed tile items, required one by one
+    looks: AHashMap<GroundItem, GroundItemLook>,
+    look_request: Option<(GroundItem, Request)>,
+}
+
+impl GroundStack {
+    /// Display stack of given tile (items looks are required again, as actions may have changed)
+    pub fn open(&mut self, row_i: i32, col_i: i32) {
+        self.tile = Some((row_i, col_i));
+        self.looks.clear();
+        self.look_request = None;
+    }
+
+    pub fn close(&mut self) {
+        self.tile = None;
+        self.mouse_hover = false;
+        self.look_request = None;
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    pub fn proceed_requests(&mut self, client: &Client, player_id: &str, state: &ZoneState) {
+        if let Some((item, request)) = self.look_request.as_mut() {
+            if let Some(data) = request.try_recv() {
+                match data
+                    .map_err(|error| error.to_string())
+                    .and_then(|description_string| Description::from_string(&description_string))
+                {
+                    Ok(description) => {
+                        let actions = description
+                            .links()
+                            .into_iter()
+                            .filter_map(|part| {
+                                part.form_action
+                                    .as_ref()
+                                    .map(|url| (part.label(), url.clone()))
+                            })
+                            .collect();
+                        let name = description.title.unwrap_or_else(|| "?".to_string());
+                        self.looks
+                            .insert(item.clone(), GroundItemLook { name, actions });
+                    }
+                    Err(error) => {
+                        error!("Error while requiring ground item description : {}", error);
+                        // Not required again until stack is opened again
+                        self.looks.insert(
+                            item.clone(),
+                            GroundItemLook {
+                                name: "?".to_string(),
+                                actions: vec![],
+                            },
+                        );
+                    }
+                }
+                self.look_request = None;
+            }
+        }
+
+        if self.look_request.is_some() {
+            return;
+        }
+        let (row_i, col_i) = match self.tile {
+            Some(tile) => tile,
+            None => return,
+        };
+        let tile_entities = match state.entities_at(row_i, col_i) {
+            Some(tile_entities) => tile_entities,
+            None => return,
+        };
+
+        let unknown_stuff = tile_entities
+            .stuffs
+            .iter()
+            .map(|stuff| GroundItem::Stuff(stuff.id))
+            .find(|item| !self.looks.contains_key(item));
+        let unknown_resource = tile_entities
+            .resources
+            .iter()
+            .map(|resource| GroundItem::Resource(resource.id.clone()))
+            .find(|item| !self.looks.contains_key(item));
+        if let Some(item) = unknown_stuff.or(unknown_resource) {
+            let url = match &item {
+                GroundItem::Stuff(stuff_id) => util::ground_stuff_look_url(player_id, *stuff_id),
+                GroundItem::Resource(resource_id) => {
+                    util::ground_resource_look_url(player_id, resource_id, row_i, col_i)
+                }
+            };
+            self.look_request = Some((item, client.get_description_request(url, None, None)));
+        }
+    }
+
+    fn item_ui(&self, ui: &mut egui::Ui, item: &GroundItem) -> Option<GroundStackEvent> {
+        let mut event = None;
+
+        ui.horizontal(|ui| match self.looks.get(item) {
+            Some(look) => {
+                ui.label(&look.name);
+                for (label, url) in &look.actions {
+                    if ui.button(label).clicked() {
+                        event = Some(GroundStackEvent::PickUp(url.clone()));
+                    }
+                }
+            }
+            None => {
+                ui.label("...");
+            }
+        });
+
+        event
+    }
+
+    pub fn ui(&mut self, egui_ctx: &Context, state: &ZoneState) -> Option<GroundStackEvent> {
+        let (row_i, col_i) = self.tile?;
+        let mut event = None;
+        let mut display = true;
+
+        let response = egui::Window::new(format!(
+            "{} ({}, {})",
+            i18n::tr("stack.title"),
+            row_i,
+            col_i
+        ))
+        .open(&mut display)
+        .default_width(STACK_WINDOW_WIDTH)
+        .show(egui_ctx, |ui| {
+            let items: Vec<GroundItem> = match state.entities_at(row_i, col_i) {
+                Some(tile_entities) => tile_entities
+                    .resources
+                    .iter()
+                    .map(|resource| GroundItem::Resource(resource.id.clone()))
+                    .chain(
+                        tile_entities
+                            .stuffs
+                            .iter()
+                            .map(|stuff| GroundItem::Stuff(stuff.id)),
+                    )
+                    .collect(),
+                None => vec![],
+            };
+
+            if items.is_empty() {
+                ui.label(i18n::tr("stack.empty"));
+            }
+            for item in &items {
+                if let Some(event_) = self.item_ui(ui, item) {
+                    event = Some(event_);
+                }
+            }
+        });
+
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        if !display {
+            self.close();
+        }
+
+        event
+    }
+}
+
+impl super::ZoneEngine {
+    /// Resources and stuffs count lying on given tile
+    pub fn ground_items_count(&self, row_i: i32, col_i: i32) -> usize {
+        self.state
+            .entities_at(row_i, col_i)
+            .map(|tile_entities| tile_entities.resources.len() + tile_entities.stuffs.len())
+            .unwrap_or(0)
+    }
+
+    pub fn proceed_ground_stack_requests(&mut self) {
+        self.ground_stack
+            .proceed_requests(&self.client, &self.state.player.id, &self.state);
+    }
+}
@@ -1,8 +1,10 @@
 use ahash::AHashMap;
 
-use crate::{entity, zone};
+use crate::{entity, tileset, zone};
 use macroquad::prelude::*;
 
+use super::index::{IndexedEntity, SpatialIndex, TileEntities};
+
 pub struct ZoneState {
     pub map: zone::map::ZoneMap,
     pub characters: AHashMap<String, entity::character::Character>,
@@ -10,7 +12,10 @@ pub struct ZoneState {
     pub player_display: CharacterDisplay,
     pub stuffs: AHashMap<i32, entity::stuff::Stuff>,
     pub resources: AHashMap<(i32, i32), Vec<entity::resource::Resource>>,
-    pub builds: AHashMap<(i32, i32), entity::build::Build>,
+    // Builds by id, their position is known by the spatial index (see `build_at`)
+    pub builds: AHashMap<i32, entity::build::Build>,
+    pub index: SpatialIndex,
+    pub autotiles: zone::autotile::Autotiles,
 }
 
 impl ZoneState {
@@ -23,60 +28,207 @@ impl ZoneState {
         resources: Vec<entity::resource::Resource>,
         builds: Vec<entity::build::Build>,
     ) -> Self {
-        let mut builds_ = AHashMap::new();
+        let player_display = CharacterDisplay::new(Vec2::new(
+            player.zone_col_i as f32 * graphics.tile_width,
+            player.zone_row_i as f32 * graphics.tile_height,
+        ));
+        let index = SpatialIndex::new(map.width, map.height);
+        // Computed by zone engine, according to terrain transitions setting
+        let autotiles = zone::autotile::Autotiles::empty(&map);
+
+        let mut state = Self {
+            map,
+            characters: AHashMap::new(),
+            player,
+            player_display,
+            stuffs: AHashMap::new(),
+            resources: AHashMap::new(),
+            builds: AHashMap::new(),
+            index,
+            autotiles,
+        };
+
+        let tiles_mapping = graphics.tiles_mapping();
         for build in builds {
-            builds_.insert((build.row_i, build.col_i), build);
+            state.insert_build(&tiles_mapping, build);
         }
-
-        let mut stuffs_ = AHashMap::new();
         for stuff in stuffs {
-            stuffs_.insert(stuff.id.clone(), stuff);
+            state.insert_stuff(&tiles_mapping, stuff);
+        }
+        for resource in resources {
+            state.add_resource(&tiles_mapping, resource);
         }
+        for character in characters {
+            state.insert_character(character);
+        }
+
+        state
+    }
 
-        let player_display = CharacterDisplay {
-            position: Vec2::new(
-                player.zone_col_i as f32 * graphics.tile_width,
-                player.zone_row_i as f32 * graphics.tile_height,
-            ),
-            ..Default::default()
+    /// Entities located on given tile
+    pub fn entities_at(&self, row_i: i32, col_i: i32) -> Option<&TileEntities> {
+        self.index.get(row_i, col_i)
+    }
+
+    /// Build located on given tile
+    pub fn build_at(&self, row_i: i32, col_i: i32) -> Option<&entity::build::Build> {
+        let indexed_build = self.index.get(row_i, col_i)?.build.as_ref()?;
+        self.builds.get(&indexed_build.id)
+    }
+
+    /// Compute terrain transitions overlays, or drop them when transitions are disabled
+    pub fn update_autotiles(
+        &mut self,
+        tiles_mapping: &tileset::TileMapping,
+        terrain_transitions: bool,
+    ) {
+        self.autotiles = if terrain_transitions {
+            zone::autotile::Autotiles::new(&self.map, tiles_mapping)
+        } else {
+            zone::autotile::Autotiles::empty(&self.map)
         };
+    }
 
-        let mut resources_: AHashMap<(i32, i32), Vec<entity::resource::Resource>> = AHashMap::new();
-        for resource in &resources {
-            resources_
-                .entry((resource.zone_row_i, resource.zone_col_i))
-                .or_insert(vec![])
-                .push(resource.clone());
+    pub fn insert_build(
+        &mut self,
+        tiles_mapping: &tileset::TileMapping,
+        build: entity::build::Build,
+    ) {
+        let tile_id = tileset::find_tile_id_from_classes(tiles_mapping, &build.get_classes());
+        // A new build replace the one which was on the tile
+        self.remove_build(build.row_i, build.col_i);
+        if let Some(tile_entities) = self.index.get_mut(build.row_i, build.col_i) {
+            tile_entities.build = Some(IndexedEntity {
+                id: build.id,
+                tile_id,
+            });
         }
-        let characters: AHashMap<String, entity::character::Character> = characters
-            .iter()
-            .map(|c| (c.id.clone(), c.clone()))
-            .collect();
+        self.builds.insert(build.id, build);
+    }
 
-        Self {
-            map,
-            characters,
-            player,
-            player_display,
-            stuffs: stuffs_,
-            resources: resources_,
-            builds: builds_,
+    pub fn remove_build(&mut self, row_i: i32, col_i: i32) -> Option<entity::build::Build> {
+        let indexed_build = self.index.get_mut(row_i, col_i)?.build.take()?;
+        self.builds.remove(&indexed_build.id)
+    }
+
+    pub fn insert_stuff(
+        &mut self,
+        tiles_mapping: &tileset::TileMapping,
+        stuff: entity::stuff::Stuff,
+    ) {
+        // Same stuff can be re-added (moved)
+        self.remove_stuff(stuff.id);
+
+        let tile_id = tileset::find_tile_id_from_classes(tiles_mapping, &stuff.get_classes());
+        if let Some(tile_entities) = self.index.get_mut(stuff.zone_row_i, stuff.zone_col_i) {
+            tile_entities.stuffs.push(IndexedEntity {
+                id: stuff.id,
+                tile_id,
+            });
+        }
+        self.stuffs.insert(stuff.id, stuff);
+    }
+
+    pub fn remove_stuff(&mut self, id: i32) -> Option<entity::stuff::Stuff> {
+        let stuff = self.stuffs.remove(&id)?;
+        if let Some(tile_entities) = self.index.get_mut(stuff.zone_row_i, stuff.zone_col_i) {
+            tile_entities.stuffs.retain(|s| s.id != id);
+        }
+        Some(stuff)
+    }
+
+    pub fn add_resource(
+        &mut self,
+        tiles_mapping: &tileset::TileMapping,
+        resource: entity::resource::Resource,
+    ) {
+        let classes = vec!["RESOURCE_GENERIC".to_string(), resource.id.clone()];
+        let tile_id = tileset::find_tile_id_from_classes(tiles_mapping, &classes);
+        if let Some(tile_entities) = self.index.get_mut(resource.zone_row_i, resource.zone_col_i) {
+            tile_entities.resources.push(IndexedEntity {
+                id: resource.id.clone(),
+                tile_id,
+            });
+        }
+        self.resources
+            .entry((resource.zone_row_i, resource.zone_col_i))
+            .or_insert(vec![])
+            .push(resource);
+    }
+
+    pub fn remove_resource(&mut self, row_i: i32, col_i: i32, id: &str) {
+        if let Some(tile_entities) = self.index.get_mut(row_i, col_i) {
+            tile_entities.resources.retain(|r| r.id != id);
+        }
+        if let Some(resources) = self.resources.get_mut(&(row_i, col_i)) {
+            resources.retain(|r| r.id != id)
+        }
+    }
+
+    pub fn insert_character(&mut self, mut character: entity::character::Character) {
+        // Character can be already known (re-entering) : keep what minimal character don't know
+        if let Some(known) = self.characters.get(&character.id) {
+            if character.name.is_empty() {
+                character.name = known.name.clone();
+                character.avatar_uuid = known.avatar_uuid.clone();
+                character.avatar_is_validated = known.avatar_is_validated;
+            }
+        }
+        self.remove_character(&character.id);
+
+        if let Some(tile_entities) = self
+            .index
+            .get_mut(character.zone_row_i, character.zone_col_i)
+        {
+            tile_entities.character_ids.push(character.id.clone());
+        }
+        self.characters.insert(character.id.clone(), character);
+    }
+
+    pub fn remove_character(&mut self, id: &str) -> Option<entity::character::Character> {
+        let character = self.characters.remove(id)?;
+        if let Some(tile_entities) = self
+            .index
+            .get_mut(character.zone_row_i, character.zone_col_i)
+        {
+            tile_entities.character_ids.retain(|c| c != id);
+        }
+        Some(character)
+    }
+
+    pub fn move_character(&mut self, id: &str, row_i: i32, col_i: i32) {
+        if let Some(mut character) = self.remove_character(id) {
+            character.zone_row_i = row_i;
+            character.zone_col_i = col_i;
+            self.insert_character(character);
         }
     }
 }
 
 pub struct CharacterDisplay {
+    // Position at the last movement step, and at the step before (see `movement::FixedTimestep`)
     pub position: Vec2,
+    pub previous_position: Vec2,
+    // Progression between previous and last step positions, for drawing
+    pub interpolation: f32,
     pub velocity: Vec2,
     pub running: Option<super::PlayerRunning>,
 }
 
-impl Default for CharacterDisplay {
-    fn default() -> Self {
+impl CharacterDisplay {
+    pub fn new(position: Vec2) -> Self {
         Self {
-            position: Vec2::new(0., 0.),
+            position,
+            previous_position: position,
+            interpolation: 0.,
             velocity: Vec2::new(0., 0.),
             running: None,
         }
     }
+
+    /// Position where to draw, interpolated between the last two movement steps
+    pub fn draw_position(&self) -> Vec2 {
+        self.previous_position
+            .lerp(self.position, self.interpolation)
+    }
 }
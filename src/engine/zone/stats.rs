@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+
+use egui::{
+    plot::{Line, Plot, PlotPoints},
+    Context,
+};
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{i18n, storage, util};
+
+use super::resume::CharacterResume;
+
+// Oldest samples are removed beyond this count
+const STATS_HISTORY_MAX_SAMPLES: usize = 500;
+const STATS_WINDOW_WIDTH: f32 = 320.;
+const STATS_GRAPH_HEIGHT: f32 = 70.;
+// Duration (seconds) used to compute values change rate
+const STATS_RATE_DURATION: f64 = 3600.;
+// Minimum duration (seconds) between two history saves
+const STATS_HISTORY_SAVE_INTERVAL: f64 = 5.;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StatsSample {
+    // Timestamp (seconds since epoch)
+    pub time: f64,
+    pub action_points: f32,
+    pub hunger: f32,
+    pub thirst: f32,
+    pub tiredness: f32,
+}
+
+impl StatsSample {
+    fn same_values(&self, other: &StatsSample) -> bool {
+        self.action_points == other.action_points
+            && self.hunger == other.hunger
+            && self.thirst == other.thirst
+            && self.tiredness == other.tiredness
+    }
+}
+
+/// Character resume values over time, with a window displaying them as graphs
+pub struct StatsHistory {
+    character_id: String,
+    samples: VecDeque<StatsSample>,
+    samples_changed: bool,
+    samples_saved_at: f64,
+    display: bool,
+    mouse_hover: bool,
+}
+
+fn storage_key(character_id: &str) -> String {
+    format!("__STATS_HISTORY__{}", character_id)
+}
+
+impl StatsHistory {
+    pub fn new(character_id: &str) -> Self {
+        Self {
+            character_id: character_id.to_string(),
+            samples: load(character_id),
+            samples_changed: false,
+            samples_saved_at: util::now(),
+            display: false,
+            mouse_hover: false,
+        }
+    }
+
+    pub fn is_display(&self) -> bool {
+        self.display
+    }
+
+    pub fn set_display(&mut self, display: bool) {
+        self.display = display;
+        if !display {
+            self.mouse_hover = false;
+        }
+    }
+
+    pub fn is_mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    /// Record resume values (only when they changed since last sample)
+    pub fn push(&mut self, resume: &CharacterResume) {
+        let sample = StatsSample {
+            time: util::now(),
+            action_points: resume.action_points,
+            hunger: resume.hungry.percent(),
+            thirst: resume.thirsty.percent(),
+            tiredness: resume.tiredness.percent(),
+        };
+        if let Some(last) = self.samples.back() {
+            if last.same_values(&sample) {
+                return;
+            }
+        }
+
+        self.samples.push_back(sample);
+        if self.samples.len() > STATS_HISTORY_MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples_changed = true;
+    }
+
+    /// Save samples if they changed, at most once per interval (or immediately when forced)
+    pub fn save(&mut self, force: bool) {
+        if !self.samples_changed
+            || (!force && util::now() - self.samples_saved_at < STATS_HISTORY_SAVE_INTERVAL)
+        {
+            return;
+        }
+
+        save(&self.character_id, &self.samples);
+        self.samples_changed = false;
+        self.samples_saved_at = util::now();
+    }
+
+    /// Value change per hour over last hour, if enough samples
+    fn rate(&self, value: fn(&StatsSample) -> f32) -> Option<f32> {
+        let last = self.samples.back()?;
+        let first = self
+            .samples
+            .iter()
+            .find(|sample| last.time - sample.time <= STATS_RATE_DURATION)?;
+        let duration = last.time - first.time;
+        if duration <= 0. {
+            return None;
+        }
+
+        Some(((value(last) - value(first)) as f64 * 3600. / duration) as f32)
+    }
+
+    fn graph_ui(&self, ui: &mut egui::Ui, name: &str, value: fn(&StatsSample) -> f32) {
+        let now = util::now();
+        let rate = match self.rate(value) {
+            Some(rate) => format!("{:+.1} / {}", rate, i18n::tr("stats.per_hour")),
+            None => "-".to_string(),
+        };
+        let current = self
+            .samples
+            .back()
+            .map(|sample| format!("{:.0}", value(sample)))
+            .unwrap_or("-".to_string());
+        ui.label(format!("{} : {} ({})", name, current, rate));
+
+        // Hours before now
+        let points: PlotPoints = self
+            .samples
+            .iter()
+            .map(|sample| [(sample.time - now) / 3600., value(sample) as f64])
+            .collect::<Vec<[f64; 2]>>()
+            .into();
+        Plot::new(format!("stats_{}", name))
+            .height(STATS_GRAPH_HEIGHT)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .allow_drag(false)
+            .allow_boxed_zoom(false)
+            .include_y(0.)
+            .show(ui, |plot_ui| plot_ui.line(Line::new(points)));
+    }
+
+    pub fn ui(&mut self, egui_ctx: &Context) {
+        let mut display = self.display;
+
+        let response = egui::Window::new(i18n::tr("stats.title"))
+            .open(&mut display)
+            .default_pos((0., 0.))
+            .default_width(STATS_WINDOW_WIDTH)
+            .show(egui_ctx, |ui| {
+                if self.samples.is_empty() {
+                    ui.label(i18n::tr("stats.no_sample"));
+                    return;
+                }
+
+                self.graph_ui(ui, i18n::tr("resume.action_points"), |sample| {
+                    sample.action_points
+                });
+                self.graph_ui(ui, i18n::tr("resume.hunger"), |sample| sample.hunger);
+                self.graph_ui(ui, i18n::tr("resume.thirst"), |sample| sample.thirst);
+                self.graph_ui(ui, i18n::tr("resume.tiredness"), |sample| sample.tiredness);
+                ui.label(i18n::tr("stats.x_axis"));
+            });
+
+        self.mouse_hover = match response {
+            Some(response) => response.response.hovered(),
+            None => false,
+        };
+        self.set_display(display);
+    }
+}
+
+impl Drop for StatsHistory {
+    fn drop(&mut self) {
+        self.save(true);
+    }
+}
+
+fn load(character_id: &str) -> VecDeque<StatsSample> {
+    storage::load_json(&storage_key(character_id)).unwrap_or_default()
+}
+
+fn save(character_id: &str, samples: &VecDeque<StatsSample>) {
+    storage::save_json(&storage_key(character_id), samples)
+}
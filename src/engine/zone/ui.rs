@@ -9,7 +9,13 @@ use crate::{
     util,
 };
 
-use super::{gui::chat::display::ChatDisplayer, UserInput};
+use super::{
+    alert::NeedAlertsEvent,
+    characters::CharactersPanelEvent,
+    gui::chat::{command, display::ChatDisplayer},
+    stack::GroundStackEvent,
+    UserInput,
+};
 
 impl super::ZoneEngine {
     pub fn ui(&mut self) -> Vec<message::MainMessage> {
@@ -94,7 +100,11 @@ impl super::ZoneEngine {
             }
 
             if self.chat_state.is_display() {
-                let chat_display = ChatDisplayer::new(&self.chat_state).ui(egui_ctx);
+                let completions = command::completions(
+                    self.chat_state.input_value(),
+                    &self.chat_command_context(),
+                );
+                let chat_display = ChatDisplayer::new(&self.chat_state, completions).ui(egui_ctx);
                 if chat_display.input_validated && self.chat_state.input_value().trim().len() > 0 {
                     // Warning: this behavior is risky : chat_state.input_value must be reset. If not, multiple input_validated will be seen
                     self.user_inputs.push(UserInput::SubmitChatInput);
@@ -110,6 +120,87 @@ impl super::ZoneEngine {
 
                 self.chat_state.update_from_display(&chat_display);
             }
+
+            if self.journal.is_display() {
+                if let Some((row_i, col_i)) = self.journal.ui(egui_ctx) {
+                    self.user_inputs
+                        .push(UserInput::CenterCameraOn(row_i, col_i));
+                }
+            }
+
+            if self.characters_panel.is_display() {
+                match self.characters_panel.ui(
+                    egui_ctx,
+                    &self.client,
+                    &self.state,
+                    &self.followed_character_id,
+                ) {
+                    Some(CharactersPanelEvent::CenterCameraOn(character_id)) => {
+                        if let Some(character) = self.state.characters.get(&character_id) {
+                            self.user_inputs.push(UserInput::CenterCameraOn(
+                                character.zone_row_i,
+                                character.zone_col_i,
+                            ));
+                        }
+                    }
+                    Some(CharactersPanelEvent::OpenCard(character_id)) => {
+                        self.description_request = Some(self.client.get_description_request(
+                            super::util::character_card_url(&self.state.player.id, &character_id),
+                            None,
+                            None,
+                        ));
+                    }
+                    Some(CharactersPanelEvent::Follow(character_id)) => {
+                        self.user_inputs
+                            .push(UserInput::FollowCharacter(Some(character_id)));
+                    }
+                    Some(CharactersPanelEvent::StopFollow) => {
+                        self.user_inputs.push(UserInput::FollowCharacter(None));
+                    }
+                    None => {}
+                }
+            }
+
+            if let Some(GroundStackEvent::PickUp(url)) = self.ground_stack.ui(egui_ctx, &self.state)
+            {
+                self.description_request =
+                    Some(self.client.get_description_request(url, None, None));
+            }
+
+            if let Some(NeedAlertsEvent::OpenSettings) = self.need_alerts.banner_ui(egui_ctx) {
+                self.need_alerts.set_display_settings(true);
+            }
+            if self.need_alerts.is_display_settings() {
+                self.need_alerts.settings_ui(egui_ctx);
+            }
+
+            if self.settings_window.is_display() {
+                let terrain_transitions = self.settings.terrain_transitions;
+                if self.settings_window.ui(egui_ctx, &mut self.settings) {
+                    self.camera.apply_settings(&self.settings);
+                    if self.settings.terrain_transitions != terrain_transitions {
+                        self.state.update_autotiles(
+                            &self.graphics.tiles_mapping(),
+                            self.settings.terrain_transitions,
+                        );
+                    }
+                }
+            }
+
+            if self.user_log_history.borrow().is_display() {
+                self.user_log_history.borrow_mut().ui(egui_ctx);
+            }
+
+            if self.stats_history.is_display() {
+                self.stats_history.ui(egui_ctx);
+            }
+
+            if self.context_menu.tile().is_some() {
+                if let Some(url) = self.context_menu.ui(egui_ctx) {
+                    self.description_request =
+                        Some(self.client.get_description_request(url, None, None));
+                }
+            }
         });
 
         messages
@@ -13,6 +13,39 @@ pub fn require_around_event(state: &super::state::ZoneState) -> String {
     })
     .unwrap()
 }
+/// Description url of a character card, as seen by the player
+pub fn character_card_url(player_id: &str, character_id: &str) -> String {
+    if player_id == character_id {
+        return format!("/_describe/character/{}/card", player_id);
+    }
+
+    format!(
+        "/_describe/character/{}/character_look/{}",
+        player_id, character_id
+    )
+}
+
+/// Description url of a stuff lying on the ground (its name and pick up actions)
+pub fn ground_stuff_look_url(player_id: &str, stuff_id: i32) -> String {
+    format!(
+        "/_describe/character/{}/ground_stuff_look/{}",
+        player_id, stuff_id
+    )
+}
+
+/// Description url of a resource lying on given tile (its name and pick up actions)
+pub fn ground_resource_look_url(
+    player_id: &str,
+    resource_id: &str,
+    row_i: i32,
+    col_i: i32,
+) -> String {
+    format!(
+        "/_describe/character/{}/ground_resource_look/{}?zone_row_i={}&zone_col_i={}",
+        player_id, resource_id, row_i, col_i
+    )
+}
+
 pub fn require_resume_text_event() -> String {
     serde_json::to_string(&event::ZoneEvent {
         event_type_name: String::from(event::CLIENT_REQUIRE_NEW_RESUME_TEXT),
@@ -55,6 +88,23 @@ pub fn live_message_event(character_id: &str, message: String) -> String {
             // TODO : should not necessary for push message
             system: false,
             silent: false,
+            private: false,
+            to_character_id: None,
+        },
+    })
+    .unwrap()
+}
+
+pub fn private_message_event(character_id: &str, to_character_id: &str, message: String) -> String {
+    serde_json::to_string(&event::ZoneEvent {
+        event_type_name: String::from(event::NEW_CHAT_MESSAGE),
+        event_type: event::ZoneEventType::NewChatMessage {
+            character_id: Some(character_id.to_string()),
+            message,
+            system: false,
+            silent: false,
+            private: true,
+            to_character_id: Some(to_character_id.to_string()),
         },
     })
     .unwrap()
@@ -19,7 +19,6 @@ impl Build {
     }
 
     pub fn get_classes(&self) -> Vec<String> {
-        // TODO perf: compute this at object creation
         let mut classes = vec!["BUILD_GENERIC".to_string()];
         classes.extend(self.classes.clone());
         classes
@@ -1,7 +1,7 @@
 use macroquad::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::types::AvatarUuid;
+use crate::{entity::tile::TransportMode, types::AvatarUuid};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Character {
@@ -15,6 +15,8 @@ pub struct Character {
     pub avatar_is_validated: bool,
     pub spritesheet_filename: Option<String>,
     pub spritesheet_set: bool,
+    #[serde(default)]
+    pub transport_mode: TransportMode,
 }
 
 impl Character {
@@ -35,6 +37,7 @@ impl Character {
             avatar_is_validated: false,
             spritesheet_filename,
             spritesheet_set: false,
+            transport_mode: TransportMode::default(),
         }
     }
 
@@ -94,6 +94,15 @@ impl Description {
     pub fn title(&self) -> String {
         self.title.clone().unwrap_or_default()
     }
+
+    /// Link parts (actions) of the description, including nested ones
+    pub fn links(&self) -> Vec<&Part> {
+        let mut links = vec![];
+        for part in &self.items {
+            part.collect_links(&mut links);
+        }
+        links
+    }
 }
 
 impl Part {
@@ -129,6 +138,15 @@ impl Part {
         self.is_link && self.form_action.is_some()
     }
 
+    fn collect_links<'a>(&'a self, links: &mut Vec<&'a Part>) {
+        if self.is_link() {
+            links.push(self);
+        }
+        for part in &self.items {
+            part.collect_links(links);
+        }
+    }
+
     pub fn is_checkbox(&self) -> bool {
         self.is_checkbox
     }
@@ -14,7 +14,6 @@ impl Stuff {
         (self.zone_row_i, self.zone_col_i)
     }
     pub fn get_classes(&self) -> Vec<String> {
-        // TODO perf: compute this at object creation
         let mut classes = vec!["STUFF_GENERIC".to_string()];
         classes.extend(self.classes.clone());
         classes.push(self.stuff_id.clone());
@@ -1,16 +1,55 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+/// Transport mode, as given by server. Modes unknown by client are kept by their name
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(from = "String", into = "String")]
 pub enum TransportMode {
-    #[serde(rename = "WALKING")]
+    #[default]
     Walking,
+    Swimming,
+    Riding,
+    Boat,
+    Other(String),
+}
+
+impl From<String> for TransportMode {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "WALKING" => TransportMode::Walking,
+            "SWIMMING" => TransportMode::Swimming,
+            "RIDING" => TransportMode::Riding,
+            "BOAT" => TransportMode::Boat,
+            _ => TransportMode::Other(value),
+        }
+    }
+}
+
+impl From<TransportMode> for String {
+    fn from(value: TransportMode) -> Self {
+        value.to_string().to_owned()
+    }
 }
 
 impl TransportMode {
     pub fn to_string(&self) -> &str {
         match self {
             TransportMode::Walking => "WALKING",
+            TransportMode::Swimming => "SWIMMING",
+            TransportMode::Riding => "RIDING",
+            TransportMode::Boat => "BOAT",
+            TransportMode::Other(value) => value,
+        }
+    }
+
+    /// Translation key of transport mode name (none for modes unknown by client)
+    pub fn name_key(&self) -> Option<&'static str> {
+        match self {
+            TransportMode::Walking => Some("transport.walking"),
+            TransportMode::Swimming => Some("transport.swimming"),
+            TransportMode::Riding => Some("transport.riding"),
+            TransportMode::Boat => Some("transport.boat"),
+            TransportMode::Other(_) => None,
         }
     }
 }
@@ -42,3 +81,24 @@ pub struct Tile {
     pub traversable: HashMap<TransportMode, bool>,
     pub hump: HashMap<TransportMode, HumpType>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_transport_modes_are_kept_by_name() {
+        let traversable: HashMap<TransportMode, bool> =
+            serde_json::from_str(r#"{"WALKING": true, "FLYING": false}"#).unwrap();
+
+        assert_eq!(traversable.get(&TransportMode::Walking), Some(&true));
+        assert_eq!(
+            traversable.get(&TransportMode::Other("FLYING".to_string())),
+            Some(&false)
+        );
+        assert_eq!(
+            serde_json::to_string(&TransportMode::Other("FLYING".to_string())).unwrap(),
+            r#""FLYING""#
+        );
+    }
+}
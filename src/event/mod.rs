@@ -104,6 +104,12 @@ pub enum ZoneEventType {
         message: String,
         system: bool,
         silent: bool,
+        // Message between player and one character
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        private: bool,
+        // Recipient of a private message
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        to_character_id: Option<String>,
     },
     AnimatedCorpseMove {
         to_row_i: i32,
@@ -147,6 +153,10 @@ pub struct NewChatMessage {
     pub character_id: Option<String>,
     pub silent: bool,
     pub system: bool,
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default)]
+    pub to_character_id: Option<String>,
 }
 
 #[derive(SerdeSerialize, SerdeDeserialize, Debug)]
@@ -262,16 +272,9 @@ impl ZoneEvent {
                     classes.push(class.to_string());
                 }
                 let mut traversable: HashMap<String, bool> = HashMap::new();
-                traversable.insert(
-                    "WALKING".to_string(),
-                    build_data["traversable"]
-                        .as_object()
-                        .unwrap()
-                        .get("WALKING")
-                        .unwrap()
-                        .as_bool()
-                        .unwrap(),
-                );
+                for (transport_mode, value) in build_data["traversable"].as_object().unwrap() {
+                    traversable.insert(transport_mode.clone(), value.as_bool().unwrap());
+                }
 
                 let produced_resource_id = match data["produced_resource_id"].as_str() {
                     Some(produced_resource_id) => Some(produced_resource_id.to_string()),
@@ -336,6 +339,8 @@ impl ZoneEvent {
                         character_id: new_chat_message.character_id,
                         system: new_chat_message.system,
                         silent: new_chat_message.silent,
+                        private: new_chat_message.private,
+                        to_character_id: new_chat_message.to_character_id,
                     },
                 })
             }
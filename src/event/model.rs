@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ItemModel {
     pub name: String,
+    // Stable identifier of the item, not depending on server language
+    #[serde(default)]
+    pub key: Option<String>,
     pub value_is_str: bool,
     pub value_is_float: bool,
     pub value_str: Option<String>,
@@ -1,17 +1,31 @@
 use image::{io::Reader as ImageReader, DynamicImage};
+use std::cell::{Ref, RefCell};
 use std::collections::HashMap;
 use std::io::Cursor;
+use std::rc::Rc;
 
 use macroquad::prelude::*;
 
 use crate::engine::zone::PlayerRunning;
-use crate::util::bytes_from_cache_or_file;
-use crate::{hardcoded::get_tiles_list, tileset, types::AvatarUuid};
+use crate::util::{bytes_from_cache_or_file, vname};
+use crate::{
+    config, settings::TilesetResolution, tileset, types::AvatarUuid, ui::utils::dpi_scale,
+};
+
+pub const TILESET_IMAGE_PATH: &str = "static/graphics.png";
+pub const TILESET_MANIFEST_PATH: &str = "static/graphics.json";
+pub const HIGH_TILESET_IMAGE_PATH: &str = "static/graphics@2x.png";
+pub const HIGH_TILESET_MANIFEST_PATH: &str = "static/graphics@2x.json";
+// Screen pixel density from which high resolution tileset is used (with Auto setting)
+const HIGH_TILESET_DPI_SCALE: f32 = 1.5;
 
 const NUMBER_START_X: f32 = 0.;
 const NUMBER_START_Y: f32 = 704.;
 const NUMBER_WIDTH: f32 = 32.;
 const NUMBER_HEIGHT: f32 = 32.;
+// Count badge digits size, relatively to tile size
+const COUNT_BADGE_DIGIT_SIZE_FACTOR: f32 = 0.35;
+const COUNT_BADGE_BACKGROUND_COLOR: Color = Color::new(0.0, 0.0, 0.0, 0.6);
 
 enum Number {
     Zero,
@@ -58,148 +72,337 @@ impl Number {
         }
     }
 
-    fn from_number(number: u32) -> (Option<Self>, Self) {
-        if number < 10 {
-            return (None, Self::from_digit(number as u8));
-        }
+    /// Digits of given number, most significant first
+    fn digits(number: u32) -> Vec<Self> {
+        number
+            .to_string()
+            .bytes()
+            .map(|digit| Self::from_digit(digit - b'0'))
+            .collect()
+    }
+}
 
-        if number < 99 {
-            let tens = (number / 10) as u8;
-            let ones = (number % 10) as u8;
-            return (Some(Self::from_digit(tens)), Self::from_digit(ones));
-        }
+pub struct Tileset {
+    pub texture: Texture2D,
+    pub bytes: Vec<u8>,
+    pub tiles_mapping: tileset::TileMapping,
+    pub atlas_scale: f32,
+    pub image_path: &'static str,
+    pub manifest_path: &'static str,
+}
 
-        (Some(Self::from_digit(9)), Self::from_digit(9))
+/// Load tileset matching given resolution setting (fallback on standard tileset)
+pub async fn load_tileset(resolution: &TilesetResolution) -> Result<Tileset, String> {
+    let high_resolution = match resolution {
+        TilesetResolution::Auto => dpi_scale() >= HIGH_TILESET_DPI_SCALE,
+        TilesetResolution::Standard => false,
+        TilesetResolution::High => true,
+    };
+
+    if high_resolution {
+        match load_tileset_files(HIGH_TILESET_IMAGE_PATH, HIGH_TILESET_MANIFEST_PATH).await {
+            Ok(tileset) => return Ok(tileset),
+            Err(error) => {
+                warn!(
+                    "High resolution tileset unavailable, use standard one : {}",
+                    error
+                );
+            }
+        }
     }
+
+    load_tileset_files(TILESET_IMAGE_PATH, TILESET_MANIFEST_PATH).await
 }
 
-#[derive(Clone)]
-pub struct Graphics {
+/// Load tileset image and its manifest (tiles definitions)
+pub async fn load_tileset_files(
+    image_path: &'static str,
+    manifest_path: &'static str,
+) -> Result<Tileset, String> {
+    let image_name = vname(image_path);
+    let manifest_name = vname(manifest_path);
+    let texture = load_texture(&image_name)
+        .await
+        .map_err(|error| format!("Unable to load '{}' : {}", image_name, error))?;
+    let bytes = load_file(&image_name)
+        .await
+        .map_err(|error| format!("Unable to load '{}' : {}", image_name, error))?;
+    let manifest_bytes = load_file(&manifest_name)
+        .await
+        .map_err(|error| format!("Unable to load '{}' : {}", manifest_name, error))?;
+
+    let manifest = tileset::manifest::TilesetManifest::from_bytes(&manifest_bytes)?;
+    let tiles_mapping =
+        tileset::loader::from_manifest(&manifest, texture.width(), texture.height())
+            .map_err(|error| format!("Invalid '{}' : {}", manifest_name, error))?;
+
+    Ok(Tileset {
+        texture,
+        bytes,
+        tiles_mapping,
+        atlas_scale: manifest.atlas_scale(),
+        image_path,
+        manifest_path,
+    })
+}
+
+// Egui tile textures not used since this duration (seconds) are dropped
+const TILE_TEXTURE_UNUSED_DURATION: f64 = 60.;
+
+/// Egui texture of a tile (with its action points overlay, if any)
+pub struct TileTexture {
+    pub handle: egui::TextureHandle,
+    pub last_used: f64,
+}
+
+/// Loaded graphics assets. Shared by all engines through `Graphics`
+pub struct Assets {
     pub tile_set_image: DynamicImage,
     pub tileset_texture: Texture2D,
     pub tiles_mapping: tileset::TileMapping,
-    pub tiles_bytes: HashMap<String, Vec<u8>>,
-    pub tiles_data: HashMap<String, egui::ImageData>,
-    pub tile_width: f32,
-    pub tile_height: f32,
+    // Tiles egui textures, produced on first use (see `Graphics::tile_texture`)
+    pub tile_textures: HashMap<String, TileTexture>,
+    // Tileset image pixels per logical pixel
+    pub atlas_scale: f32,
     pub avatars: HashMap<AvatarUuid, Texture2D>,
     pub illustrations: HashMap<String, egui::ImageData>,
     pub character_spritesheets: HashMap<String, (String, Texture2D)>,
 }
 
-impl Graphics {
-    pub fn new(
-        tileset_texture: Texture2D,
-        tile_set_bytes: Vec<u8>,
-        tiles_mapping: tileset::TileMapping,
-        tile_width: f32,
-        tile_height: f32,
-    ) -> Self {
+impl Assets {
+    fn decode_tileset_image(tileset: &Tileset) -> DynamicImage {
         // FIXME manage errors
-        let tile_set_image = ImageReader::new(Cursor::new(tile_set_bytes))
+        ImageReader::new(Cursor::new(&tileset.bytes))
             .with_guessed_format()
             .unwrap()
             .decode()
-            .unwrap();
-
-        // TODO : crop all tiles images and make egui texture with it, then store it
-        let mut tiles_bytes = HashMap::new();
-        let mut tiles_data = HashMap::new();
-        for (tile_id, row_i, col_i, _) in get_tiles_list() {
-            let x = col_i as f32 * tile_width;
-            let y = row_i as f32 * tile_height;
-            let tile_image =
-                tile_set_image.crop_imm(x as u32, y as u32, tile_width as u32, tile_height as u32);
-            let tile_bytes = tile_image.as_bytes().to_vec();
-            let image_data = egui::ImageData::Color(egui::ColorImage::from_rgba_unmultiplied(
-                [tile_width as usize, tile_height as usize],
-                &tile_bytes,
-            ));
-            tiles_bytes.insert(tile_id.to_string(), tile_bytes);
-            tiles_data.insert(tile_id.to_string(), image_data);
+            .unwrap()
+    }
+
+    /// Tileset image rect of given logical rect
+    pub fn atlas_rect(&self, rect: Rect) -> Rect {
+        Rect::new(
+            rect.x * self.atlas_scale,
+            rect.y * self.atlas_scale,
+            rect.w * self.atlas_scale,
+            rect.h * self.atlas_scale,
+        )
+    }
+
+    /// Tile source of given tile id (UNKNOWN tile if not existing, like after tileset reload)
+    fn tile_source(&self, tile_id: &str) -> &tileset::source::TileSource {
+        match self.tiles_mapping.get(tile_id) {
+            Some(tile_source) => tile_source,
+            None => self
+                .tiles_mapping
+                .get("UNKNOWN")
+                .expect("Tile id UNKNOWN is unknown"),
         }
+    }
+
+    /// Tileset image part of given logical rect
+    fn crop(&self, rect: Rect) -> DynamicImage {
+        let rect = self.atlas_rect(rect);
+        self.tile_set_image
+            .crop_imm(rect.x as u32, rect.y as u32, rect.w as u32, rect.h as u32)
+    }
+
+    /// Digit image resized to given logical size
+    fn number_image(&self, number: &Number, width: f32, height: f32) -> DynamicImage {
+        self.crop(Rect::new(
+            number.x(),
+            NUMBER_START_Y,
+            NUMBER_WIDTH,
+            NUMBER_HEIGHT,
+        ))
+        .resize_exact(
+            (width * self.atlas_scale) as u32,
+            (height * self.atlas_scale) as u32,
+            image::imageops::FilterType::Nearest,
+        )
+    }
+}
+
+/// Handle on graphics assets. Cloning it is cheap and all clones share the same assets : an
+/// illustration, avatar or spritesheet loaded from anywhere is immediately visible by all engines
+#[derive(Clone)]
+pub struct Graphics {
+    assets: Rc<RefCell<Assets>>,
+    // Logical tile size (see config::TILE_SIZE)
+    pub tile_width: f32,
+    pub tile_height: f32,
+}
+
+impl Graphics {
+    pub fn new(tileset: Tileset) -> Self {
+        let tile_set_image = Assets::decode_tileset_image(&tileset);
 
         Self {
-            tile_set_image,
-            tileset_texture,
-            tiles_mapping,
-            tiles_bytes,
-            tiles_data,
-            tile_width,
-            tile_height,
-            avatars: HashMap::new(),
-            illustrations: HashMap::new(),
-            character_spritesheets: HashMap::new(),
+            assets: Rc::new(RefCell::new(Assets {
+                tile_set_image,
+                tileset_texture: tileset.texture,
+                tiles_mapping: tileset.tiles_mapping,
+                tile_textures: HashMap::new(),
+                atlas_scale: tileset.atlas_scale,
+                avatars: HashMap::new(),
+                illustrations: HashMap::new(),
+                character_spritesheets: HashMap::new(),
+            })),
+            tile_width: config::TILE_SIZE,
+            tile_height: config::TILE_SIZE,
         }
     }
 
-    pub fn find_tile_id_from_classes(&self, classes: &Vec<String>) -> String {
-        for class in classes.iter().rev() {
-            if self.tiles_mapping.contains_key(class) {
-                return class.clone();
-            }
+    pub fn tiles_mapping(&self) -> Ref<'_, tileset::TileMapping> {
+        Ref::map(self.assets.borrow(), |assets| &assets.tiles_mapping)
+    }
+
+    /// Use new tileset (after tileset files changed) and keep other loaded graphics
+    pub fn replace_tileset(&self, tileset: Tileset) {
+        let tile_set_image = Assets::decode_tileset_image(&tileset);
+        let mut assets = self.assets.borrow_mut();
+        assets.tile_set_image = tile_set_image;
+        assets.tileset_texture = tileset.texture;
+        assets.tiles_mapping = tileset.tiles_mapping;
+        assets.tile_textures.clear();
+        assets.atlas_scale = tileset.atlas_scale;
+    }
+
+    /// Egui texture of given tile, with action points overlay if cost is given. Texture is
+    /// produced and uploaded on first use, then shared by all egui windows
+    pub fn tile_texture(
+        &self,
+        egui_ctx: &egui::Context,
+        tile_id: &str,
+        cost: Option<f32>,
+    ) -> Option<egui::TextureHandle> {
+        let name = match cost {
+            Some(cost) => format!("{}__{}AP", tile_id, cost),
+            None => tile_id.to_string(),
+        };
+        let now = get_time();
+
+        if let Some(tile_texture) = self.assets.borrow_mut().tile_textures.get_mut(&name) {
+            tile_texture.last_used = now;
+            return Some(tile_texture.handle.clone());
         }
-        return "UNKNOWN".to_string();
+
+        let image_data = match cost {
+            Some(cost) => self.tile_with_ap(tile_id, cost),
+            None => self.tile_image(tile_id),
+        }?;
+        let handle = egui_ctx.load_texture(&name, image_data, egui::TextureFilter::Linear);
+        self.assets.borrow_mut().tile_textures.insert(
+            name,
+            TileTexture {
+                handle: handle.clone(),
+                last_used: now,
+            },
+        );
+
+        Some(handle)
     }
 
-    pub fn tile_with_ap(&self, tile_id: &str, cost: f32) -> Option<egui::ImageData> {
-        if let Some(sprite) = self.tiles_mapping.get(tile_id) {
-            let (tens, ones) = Number::from_number(cost as u32);
-
-            let ones_image = self
-                .tile_set_image
-                .crop_imm(
-                    ones.x() as u32,
-                    NUMBER_START_Y as u32,
-                    NUMBER_WIDTH as u32,
-                    NUMBER_HEIGHT as u32,
-                )
-                .resize(
-                    (NUMBER_WIDTH / 2.) as u32,
-                    (NUMBER_HEIGHT / 2.0) as u32,
-                    image::imageops::FilterType::Nearest,
-                );
+    /// Drop egui tile textures which have not been used for a while
+    pub fn evict_unused_tile_textures(&self, now: f64) {
+        self.assets
+            .borrow_mut()
+            .tile_textures
+            .retain(|_, tile_texture| now - tile_texture.last_used < TILE_TEXTURE_UNUSED_DURATION);
+    }
 
-            let mut final_image = self.tile_set_image.crop_imm(
-                sprite.sprites[0].x as u32,
-                sprite.sprites[0].y as u32,
-                sprite.width as u32,
-                sprite.height as u32,
-            );
-            image::imageops::overlay(
-                &mut final_image,
-                &ones_image,
-                (sprite.width / 2.0) as i64,
-                (sprite.height / 2.0) as i64,
-            );
+    fn tile_image(&self, tile_id: &str) -> Option<egui::ImageData> {
+        let assets = self.assets.borrow();
+        let tile_source = assets.tiles_mapping.get(tile_id)?;
+        let tile_image = assets.crop(tile_source.to_rect(0));
+
+        Some(egui::ImageData::Color(
+            egui::ColorImage::from_rgba_unmultiplied(
+                [tile_image.width() as usize, tile_image.height() as usize],
+                &tile_image.to_rgba8(),
+            ),
+        ))
+    }
+
+    pub fn illustration(&self, illustration_name: &str) -> Option<egui::ImageData> {
+        self.assets
+            .borrow()
+            .illustrations
+            .get(illustration_name)
+            .cloned()
+    }
 
-            if let Some(tens) = tens {
-                let tens_image = self
-                    .tile_set_image
-                    .crop_imm(
-                        tens.x() as u32,
-                        NUMBER_START_Y as u32,
-                        NUMBER_WIDTH as u32,
-                        NUMBER_HEIGHT as u32,
-                    )
-                    .resize(
-                        (NUMBER_WIDTH / 2.) as u32,
-                        (NUMBER_HEIGHT / 2.0) as u32,
-                        image::imageops::FilterType::Nearest,
-                    );
+    pub fn avatar(&self, avatar_uuid: &AvatarUuid) -> Option<Texture2D> {
+        self.assets.borrow().avatars.get(avatar_uuid).copied()
+    }
+
+    /// Filename of the currently loaded spritesheet of given character
+    pub fn character_spritesheet_filename(&self, character_id: &str) -> Option<String> {
+        self.assets
+            .borrow()
+            .character_spritesheets
+            .get(character_id)
+            .map(|(filename, _)| filename.clone())
+    }
 
+    /// Draw a part of the tileset. Source is a logical rect and, if not given, destination size
+    /// is its logical size (whatever the tileset resolution)
+    pub fn draw_tileset_part(&self, x: f32, y: f32, params: DrawTextureParams) {
+        self.draw_tileset_part_ex(x, y, WHITE, params)
+    }
+
+    /// Like `draw_tileset_part`, tinted with given color
+    pub fn draw_tileset_part_ex(
+        &self,
+        x: f32,
+        y: f32,
+        color: Color,
+        mut params: DrawTextureParams,
+    ) {
+        let assets = self.assets.borrow();
+        if let Some(source) = params.source {
+            if params.dest_size.is_none() {
+                params.dest_size = Some(Vec2::new(source.w, source.h));
+            }
+            params.source = Some(assets.atlas_rect(source));
+        }
+
+        draw_texture_ex(assets.tileset_texture, x, y, color, params);
+    }
+
+    pub fn find_tile_id_from_classes(&self, classes: &Vec<String>) -> String {
+        tileset::find_tile_id_from_classes(&self.assets.borrow().tiles_mapping, classes)
+    }
+
+    pub fn tile_with_ap(&self, tile_id: &str, cost: f32) -> Option<egui::ImageData> {
+        let assets = self.assets.borrow();
+        if let Some(sprite) = assets.tiles_mapping.get(tile_id) {
+            let digits = Number::digits(cost as u32);
+            // Digits take half of the tile, or less when there are more than two
+            let digit_width = (sprite.width / 2.0).min(sprite.width / digits.len() as f32);
+            let digit_height = sprite.height / 2.0;
+
+            let mut final_image = assets.crop(Rect::new(
+                sprite.sprites[0].x,
+                sprite.sprites[0].y,
+                sprite.width,
+                sprite.height,
+            ));
+            for (i, digit) in digits.iter().enumerate() {
+                let digit_image = assets.number_image(digit, digit_width, digit_height);
+                let digit_x = sprite.width - digit_width * (digits.len() - i) as f32;
                 image::imageops::overlay(
                     &mut final_image,
-                    &tens_image,
-                    0,
-                    (sprite.height / 2.0) as i64,
+                    &digit_image,
+                    (digit_x * assets.atlas_scale) as i64,
+                    (digit_height * assets.atlas_scale) as i64,
                 );
             }
 
             return Some(egui::ImageData::Color(
                 egui::ColorImage::from_rgba_unmultiplied(
-                    [self.tile_width as usize, self.tile_height as usize],
-                    &final_image.as_bytes().to_vec(),
+                    [final_image.width() as usize, final_image.height() as usize],
+                    &final_image.to_rgba8(),
                 ),
             ));
         }
@@ -225,7 +428,13 @@ impl Graphics {
             None => (0., 640.),
         };
         let source = Rect::new(x, y, 64., 64.);
-        if let Some((_, texture)) = self.character_spritesheets.get(character_id) {
+        let texture = self
+            .assets
+            .borrow()
+            .character_spritesheets
+            .get(character_id)
+            .map(|(_, texture)| *texture);
+        if let Some(texture) = texture {
             let camera_dest_x = dest_x / area_width;
             // Invert the value because the camera is Y inverted
             let camera_dest_y = -(dest_y / area_height);
@@ -233,7 +442,7 @@ impl Graphics {
             let dest_size_y = (self.tile_height / area_height) * 2.;
 
             draw_texture_ex(
-                *texture,
+                texture,
                 camera_dest_x - (dest_size_x / 3.7),
                 camera_dest_y + dest_size_y,
                 WHITE,
@@ -258,17 +467,16 @@ impl Graphics {
         background_params: Option<DrawTextureParams>,
         foreground_params: Option<DrawTextureParams>,
     ) {
+        let assets = self.assets.borrow();
         let camera_dest_x = dest_x / area_width;
         // Invert the value because the camera is Y inverted
         let camera_dest_y = -(dest_y / area_height);
 
         // Draw tile background
         if let Some(background_tile_id_) = background_tile_id {
-            let background_source = self
-                .tiles_mapping
-                .get(background_tile_id_)
-                .expect(&format!("Tile id {} is unknown", background_tile_id_));
-            let background_source_rect = background_source.to_rect(tick_i);
+            let background_source = assets.tile_source(background_tile_id_);
+            let background_source_rect =
+                background_source.to_rect(background_source.frame_at(get_time()).unwrap_or(tick_i));
 
             let dest_size_x = self.tile_width / area_width;
             let dest_size_y = self.tile_height / area_height;
@@ -281,21 +489,13 @@ impl Graphics {
             background_params.dest_size = Some(Vec2::new(dest_size_x, dest_size_y));
             background_params.flip_y = true; // Invert on Y because camera is Y inverted
 
-            draw_texture_ex(
-                self.tileset_texture,
-                camera_dest_x,
-                camera_dest_y,
-                WHITE,
-                background_params,
-            );
+            self.draw_tileset_part(camera_dest_x, camera_dest_y, background_params);
         }
 
         // Draw tile foreground
-        let foreground_source = self
-            .tiles_mapping
-            .get(foreground_tile_id)
-            .expect(&format!("Tile id {} is unknown", foreground_tile_id));
-        let foreground_source_rect = foreground_source.to_rect(tick_i);
+        let foreground_source = assets.tile_source(foreground_tile_id);
+        let foreground_source_rect =
+            foreground_source.to_rect(foreground_source.frame_at(get_time()).unwrap_or(tick_i));
 
         let dest_size_x = self.tile_width / area_width;
         let dest_size_y = self.tile_height / area_height;
@@ -308,13 +508,86 @@ impl Graphics {
         foreground_params.dest_size = Some(Vec2::new(dest_size_x, dest_size_y));
         foreground_params.flip_y = true; // Invert on Y because camera is Y inverted
 
-        draw_texture_ex(
-            self.tileset_texture,
-            camera_dest_x,
+        self.draw_tileset_part(camera_dest_x, camera_dest_y, foreground_params);
+    }
+
+    /// Draw a tile (without background) tinted with given color (like transparent ghosts)
+    pub fn draw_colored_tile_in_camera(
+        &self,
+        area_width: f32,
+        area_height: f32,
+        dest_x: f32,
+        dest_y: f32,
+        tile_id: &str,
+        tick_i: i16,
+        color: Color,
+    ) {
+        let source_rect = {
+            let assets = self.assets.borrow();
+            let source = assets.tile_source(tile_id);
+            source.to_rect(source.frame_at(get_time()).unwrap_or(tick_i))
+        };
+
+        self.draw_tileset_part_ex(
+            dest_x / area_width,
+            // Invert the value because the camera is Y inverted
+            -(dest_y / area_height),
+            color,
+            DrawTextureParams {
+                source: Some(source_rect),
+                dest_size: Some(Vec2::new(
+                    self.tile_width / area_width,
+                    self.tile_height / area_height,
+                )),
+                flip_y: true, // Invert on Y because camera is Y inverted
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Draw given number (with tileset digits) in the bottom right corner of a tile
+    pub fn draw_count_badge_in_camera(
+        &self,
+        area_width: f32,
+        area_height: f32,
+        dest_x: f32,
+        dest_y: f32,
+        count: u32,
+    ) {
+        let digits = Number::digits(count);
+        let digit_width = self.tile_width * COUNT_BADGE_DIGIT_SIZE_FACTOR;
+        let digit_height = self.tile_height * COUNT_BADGE_DIGIT_SIZE_FACTOR;
+        let badge_x = dest_x + self.tile_width - digit_width * digits.len() as f32;
+        // Invert the value because the camera is Y inverted
+        let camera_dest_y = -(dest_y / area_height);
+
+        draw_rectangle(
+            badge_x / area_width,
             camera_dest_y,
-            WHITE,
-            foreground_params,
+            (digit_width * digits.len() as f32) / area_width,
+            digit_height / area_height,
+            COUNT_BADGE_BACKGROUND_COLOR,
         );
+        for (i, digit) in digits.iter().enumerate() {
+            self.draw_tileset_part(
+                (badge_x + digit_width * i as f32) / area_width,
+                camera_dest_y,
+                DrawTextureParams {
+                    source: Some(Rect::new(
+                        digit.x(),
+                        NUMBER_START_Y,
+                        NUMBER_WIDTH,
+                        NUMBER_HEIGHT,
+                    )),
+                    dest_size: Some(Vec2::new(
+                        digit_width / area_width,
+                        digit_height / area_height,
+                    )),
+                    flip_y: true, // Invert on Y because camera is Y inverted
+                    ..Default::default()
+                },
+            );
+        }
     }
 
     pub fn draw_tile_highlight(
@@ -334,23 +607,26 @@ impl Graphics {
         );
     }
 
-    pub fn add_avatar_texture(&mut self, avatar_uuid: AvatarUuid, texture: Texture2D) {
-        self.avatars.insert(avatar_uuid, texture);
+    pub fn add_avatar_texture(&self, avatar_uuid: AvatarUuid, texture: Texture2D) {
+        self.assets
+            .borrow_mut()
+            .avatars
+            .insert(avatar_uuid, texture);
     }
 
     pub fn add_character_spritesheet(
-        &mut self,
+        &self,
         character_id: &str,
         filename: &str,
         texture: Texture2D,
     ) {
-        dbg!(self.character_spritesheets.get(character_id));
-        self.character_spritesheets
+        self.assets
+            .borrow_mut()
+            .character_spritesheets
             .insert(character_id.to_string(), (filename.to_string(), texture));
-        dbg!(self.character_spritesheets.get(character_id));
     }
 
-    pub async fn load_illustration(&mut self, illustration_name: &str) {
+    pub async fn load_illustration(&self, illustration_name: &str) {
         match bytes_from_cache_or_file(&format!("media/{}", illustration_name), true).await {
             Ok(illustration_bytes) => {
                 // TODO : used to determine image size, but some heavy no ?
@@ -368,7 +644,9 @@ impl Graphics {
                         ],
                         &illustration_image.to_rgba8(),
                     ));
-                self.illustrations
+                self.assets
+                    .borrow_mut()
+                    .illustrations
                     .insert(illustration_name.to_string(), illustration_data);
             }
             Err(error) => {
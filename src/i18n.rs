@@ -0,0 +1,351 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    French,
+    English,
+}
+
+static CURRENT_LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+// Translation key, french text, english text
+const CATALOG: &[(&str, &str, &str)] = &[
+    ("loading", "Chargement ...", "Loading ..."),
+    (
+        "account_created",
+        "Compté créé, identifiez-vous",
+        "Account created, please log in",
+    ),
+    ("unknown_error", "Erreur inconnue", "Unknown error"),
+    ("error", "Erreur", "Error"),
+    (
+        "web_socket_error",
+        "Erreur de connexion web socket",
+        "Web socket connection error",
+    ),
+    // Root screen
+    (
+        "root.title",
+        "Se connecter / Créer un compte",
+        "Log in / Create an account",
+    ),
+    ("root.login", "Login: ", "Login: "),
+    ("root.password", "Mot de passe: ", "Password: "),
+    ("root.remember_me", "Rester connecté", "Stay logged in"),
+    ("root.log_in", "Se connecter", "Log in"),
+    ("root.password_lost", "Mot de passe perdu", "Password lost"),
+    (
+        "root.create_account",
+        "Créer un compte",
+        "Create an account",
+    ),
+    (
+        "root.auth_failed",
+        "Authentification échoué",
+        "Authentication failed",
+    ),
+    // Descriptions
+    ("description.close", "Fermer", "Close"),
+    ("description.previous", "Précédent", "Previous"),
+    ("description.submit", "Valider", "Submit"),
+    // Character resume items names (as sent by server)
+    ("resume.health", "PV", "HP"),
+    ("resume.action_points", "PA", "AP"),
+    ("resume.hunger", "Faim", "Hunger"),
+    ("resume.thirst", "Soif", "Thirst"),
+    ("resume.tiredness", "Fatigue", "Tiredness"),
+    ("resume.can_drink", "A boire", "Can drink"),
+    ("resume.can_eat", "A manger", "Can eat"),
+    ("resume.follow", "Suivis", "Followed"),
+    ("resume.followers", "Suiveurs", "Followers"),
+    ("resume.fighters", "Combattants", "Fighters"),
+    ("resume.messages", "Messages", "Messages"),
+    // Character resume items values
+    ("resume.value.ok", "Ok", "Ok"),
+    ("resume.value.middle", "Moyen", "Medium"),
+    ("resume.value.bad", "Mauvais", "Bad"),
+    ("resume.value.critical", "Critique", "Critical"),
+    ("resume.value.yes", "Oui", "Yes"),
+    ("resume.value.no", "Non", "No"),
+    ("resume.value.low", "Faible", "Low"),
+    // Transport modes
+    ("transport.walking", "À pied", "Walking"),
+    ("transport.swimming", "À la nage", "Swimming"),
+    ("transport.riding", "À cheval", "Riding"),
+    ("transport.boat", "En bateau", "By boat"),
+    // Indicators
+    ("indicator.free_look", "Vue libre", "Free look"),
+    ("indicator.journal", "Journal", "Journal"),
+    ("indicator.characters", "Personnages", "Characters"),
+    ("indicator.stats", "Statistiques", "Statistics"),
+    ("indicator.alerts", "Alertes", "Alerts"),
+    ("indicator.messages", "Messages", "Messages"),
+    ("indicator.settings", "Réglages", "Settings"),
+    // Chat
+    ("chat.channel.zone", "Zone", "Zone"),
+    ("chat.channel.system", "Système", "System"),
+    ("chat.channel.private", "Privé", "Private"),
+    (
+        "chat.search_hint",
+        "Texte ou personnage",
+        "Text or character",
+    ),
+    ("chat.hide", "Masquer", "Hide"),
+    (
+        "chat.hidden_senders",
+        "Personnages masqués",
+        "Hidden characters",
+    ),
+    ("chat.show_again", "Réafficher", "Show again"),
+    (
+        "chat.unknown_command",
+        "Commande inconnue",
+        "Unknown command",
+    ),
+    ("chat.see_help", "voir /help", "see /help"),
+    ("chat.usage", "Usage", "Usage"),
+    (
+        "chat.command.me",
+        "Décrire une action de votre personnage",
+        "Describe an action of your character",
+    ),
+    (
+        "chat.command.clear",
+        "Effacer les messages de l'onglet courant",
+        "Clear messages of current tab",
+    ),
+    (
+        "chat.command.zone",
+        "Revenir à l'onglet de zone et y envoyer un message",
+        "Go back to zone tab and send a message to it",
+    ),
+    ("chat.command.help", "Lister les commandes", "List commands"),
+    (
+        "chat.command.w",
+        "Envoyer un message privé",
+        "Send a private message",
+    ),
+    // Context menu
+    ("context.tile", "Case", "Tile"),
+    ("context.nothing", "Rien ici", "Nothing here"),
+    ("context.no_action", "Aucune action", "No action"),
+    ("context.you", "vous", "you"),
+    // Ground stack
+    ("stack.title", "Au sol", "On the ground"),
+    ("stack.empty", "Plus rien ici", "Nothing left here"),
+    // Constructions
+    (
+        "construction.in_progress",
+        "En construction",
+        "Under construction",
+    ),
+    (
+        "construction.continue",
+        "Clic ou Espace : continuer la construction",
+        "Click or Space : continue construction",
+    ),
+    // Journal
+    ("journal.title", "Journal", "Journal"),
+    ("journal.search", "Rechercher", "Search"),
+    ("journal.character_enter", "Arrivée", "Arrival"),
+    ("journal.character_exit", "Départ", "Departure"),
+    ("journal.new_build", "Construction", "Construction"),
+    ("journal.remove_build", "Démolition", "Demolition"),
+    ("journal.ground_stuff_added", "Objet déposé", "Item dropped"),
+    (
+        "journal.ground_stuff_removed",
+        "Objet ramassé",
+        "Item picked up",
+    ),
+    (
+        "journal.ground_resource_added",
+        "Ressource déposée",
+        "Resource dropped",
+    ),
+    (
+        "journal.ground_resource_removed",
+        "Ressource ramassée",
+        "Resource picked up",
+    ),
+    // Characters window
+    ("characters.title", "Personnages", "Characters"),
+    ("characters.sort_by", "Trier par", "Sort by"),
+    ("characters.sort_distance", "Distance", "Distance"),
+    ("characters.sort_name", "Nom", "Name"),
+    ("characters.unfollow", "Ne plus suivre", "Stop following"),
+    (
+        "characters.nobody",
+        "Personne d'autre ici",
+        "Nobody else here",
+    ),
+    ("characters.unknown", "Inconnu", "Unknown"),
+    ("characters.tiles", "cases", "tiles"),
+    ("characters.card", "Fiche", "Card"),
+    ("characters.follow", "Suivre", "Follow"),
+    // Statistics window
+    ("stats.title", "Statistiques", "Statistics"),
+    ("stats.per_hour", "heure", "hour"),
+    ("stats.no_sample", "Pas encore de relevé", "No sample yet"),
+    (
+        "stats.x_axis",
+        "Abscisse : heures écoulées",
+        "X axis : elapsed hours",
+    ),
+    // Need alerts
+    ("alert.health", "Santé", "Health"),
+    ("alert.threshold", "seuil", "threshold"),
+    ("alert.snooze", "Rappeler dans", "Remind in"),
+    ("alert.settings", "Réglages", "Settings"),
+    ("alert.settings_title", "Alertes de besoins", "Need alerts"),
+    (
+        "alert.when_below",
+        "Alerter quand la barre descend sous :",
+        "Alert when bar goes below :",
+    ),
+    ("alert.or_worse", "ou pire", "or worse"),
+    ("alert.never", "Jamais", "Never"),
+    ("alert.remind_after", "Rappel après", "Remind after"),
+    // User logs
+    (
+        "log.new_build",
+        "Un bâtiment a été construit",
+        "A building was built",
+    ),
+    (
+        "log.character_enter",
+        "Un personnage vient d'arriver",
+        "A character just arrived",
+    ),
+    ("log.info", "Informations", "Informations"),
+    ("log.error", "Erreurs", "Errors"),
+    ("log.title", "Historique des messages", "Messages history"),
+    ("log.copy", "Copier", "Copy"),
+    // Settings window
+    ("settings.title", "Réglages", "Settings"),
+    ("settings.language", "Langue", "Language"),
+    ("settings.min_zoom", "Zoom minimum", "Minimum zoom"),
+    ("settings.max_zoom", "Zoom maximum", "Maximum zoom"),
+    (
+        "settings.speech_bubbles",
+        "Bulles de dialogue au-dessus des personnages",
+        "Speech bubbles above characters",
+    ),
+    (
+        "settings.terrain_transitions",
+        "Transitions entre terrains",
+        "Transitions between terrains",
+    ),
+];
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::French, Language::English];
+
+    /// Language name, in that language
+    pub fn name(&self) -> &str {
+        match self {
+            Language::French => "Français",
+            Language::English => "English",
+        }
+    }
+
+    fn index(&self) -> u8 {
+        match self {
+            Language::French => 0,
+            Language::English => 1,
+        }
+    }
+}
+
+pub fn set_language(language: Language) {
+    CURRENT_LANGUAGE.store(language.index(), Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match CURRENT_LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::English,
+        _ => Language::French,
+    }
+}
+
+/// Text of given key in current language (french if current language is not known)
+pub fn tr(key: &'static str) -> &'static str {
+    match CATALOG.iter().find(|(key_, _, _)| *key_ == key) {
+        Some((_, french, english)) => match CURRENT_LANGUAGE.load(Ordering::Relaxed) {
+            1 => english,
+            _ => french,
+        },
+        None => key,
+    }
+}
+
+/// Key directly under given prefix (not in a sub namespace, like "resume.value." keys are for
+/// "resume." prefix) matching given text. Text can be the key itself (without prefix) or its
+/// translation in any language. Comparison ignores case
+pub fn key_from_text(prefix: &str, text: &str) -> Option<&'static str> {
+    let text = text.to_lowercase();
+    CATALOG
+        .iter()
+        .filter(|(key, _, _)| {
+            key.strip_prefix(prefix)
+                .map(|name| !name.contains('.'))
+                .unwrap_or(false)
+        })
+        .find(|(key, french, english)| {
+            key[prefix.len()..].to_lowercase() == text
+                || french.to_lowercase() == text
+                || english.to_lowercase() == text
+        })
+        .map(|(key, _, _)| *key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_from_text_matches_key_french_and_english() {
+        assert_eq!(key_from_text("resume.", "hunger"), Some("resume.hunger"));
+        assert_eq!(key_from_text("resume.", "Faim"), Some("resume.hunger"));
+        assert_eq!(key_from_text("resume.", "Hunger"), Some("resume.hunger"));
+        assert_eq!(
+            key_from_text("resume.value.", "Critique"),
+            Some("resume.value.critical")
+        );
+    }
+
+    #[test]
+    fn key_from_text_ignores_case_in_every_language() {
+        assert_eq!(key_from_text("resume.", "HUNGER"), Some("resume.hunger"));
+        assert_eq!(key_from_text("resume.", "faim"), Some("resume.hunger"));
+        assert_eq!(key_from_text("resume.", "pv"), Some("resume.health"));
+        assert_eq!(
+            key_from_text("resume.value.", "MOYEN"),
+            Some("resume.value.middle")
+        );
+    }
+
+    #[test]
+    fn key_from_text_only_matches_given_prefix() {
+        assert_eq!(
+            key_from_text("resume.value.", "ok"),
+            Some("resume.value.ok")
+        );
+        assert_eq!(key_from_text("transport.", "Faim"), None);
+        assert_eq!(key_from_text("resume.", "unknown"), None);
+    }
+
+    #[test]
+    fn key_from_text_ignores_sub_namespaces() {
+        // Values are not items
+        assert_eq!(key_from_text("resume.", "Oui"), None);
+        assert_eq!(key_from_text("resume.", "Faible"), None);
+        assert_eq!(key_from_text("resume.", "Ok"), None);
+        assert_eq!(key_from_text("resume.", "value.ok"), None);
+        assert_eq!(
+            key_from_text("resume.value.", "Oui"),
+            Some("resume.value.yes")
+        );
+    }
+}
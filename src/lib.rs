@@ -0,0 +1,7 @@
+// Modules needing neither window nor server, shared by game and tools binaries (see src/bin)
+pub mod config;
+pub mod entity;
+pub mod hardcoded;
+pub mod tileset;
+pub mod types;
+pub mod zone;
@@ -1,10 +1,12 @@
+use std::{cell::RefCell, rc::Rc};
+
 use default_env::default_env;
 use engine::world::WorldEngine;
 use macroquad::prelude::*;
 use util::texture_from_cache_or_from_file;
 
 use crate::{
-    ui::utils::{egui_scale, loaded, open_url},
+    ui::utils::{dpi_scale, egui_scale, loaded, open_url},
     util::{set_auth_token, set_remember_me, vname},
 };
 
@@ -14,20 +16,19 @@ use crate::ui::utils::reload_page;
 pub mod action;
 pub mod animation;
 pub mod client;
-pub mod config;
 pub mod description;
 pub mod engine;
-pub mod entity;
 pub mod event;
 pub mod graphics;
-pub mod hardcoded;
+pub mod i18n;
 pub mod media;
 pub mod message;
-pub mod tileset;
-pub mod types;
+pub mod settings;
+pub mod storage;
 pub mod ui;
 pub mod util;
-pub mod zone;
+
+pub use rollgui2::{config, entity, hardcoded, tileset, types, zone};
 
 const SERVER_ADDRESS: &'static str = default_env!("SERVER_ADDRESS", "http://127.0.0.1:5000");
 const VERSION: &str = default_env!("CARGO_PKG_VERSION", "0.1.0");
@@ -44,36 +45,58 @@ fn window_conf() -> Conf {
         window_resizable: true,
         window_width: 1280,
         window_height: 800,
+        // Framebuffer use physical pixels (sizes given to macroquad stay logical ones)
+        high_dpi: true,
         ..Default::default()
     }
 }
 #[macroquad::main(window_conf)]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Start rollgui2 ({})", VERSION);
-    // FIXME : manage errors
-    let graphics_name = vname("static/graphics.png");
-    let tile_set = load_texture(&graphics_name).await.unwrap();
-    let tile_set_bytes = load_file(&graphics_name).await.unwrap();
-    let tiles_mapping = tileset::loader::from_list(hardcoded::get_tiles_list(), 32., 32.);
-    let mut graphics = graphics::Graphics::new(tile_set, tile_set_bytes, tiles_mapping, 32., 32.);
+    let settings = settings::Settings::load();
+    i18n::set_language(settings.language);
+    let tileset = graphics::load_tileset(&settings.tileset_resolution).await?;
+    let (tileset_image_path, tileset_manifest_path) = (tileset.image_path, tileset.manifest_path);
+    let graphics = graphics::Graphics::new(tileset);
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut tileset_watcher =
+        tileset::watcher::TilesetWatcher::new(vec![tileset_image_path, tileset_manifest_path]);
 
     let root_illustration_name = vname("root.png");
     info!("Load root illustration {}", root_illustration_name);
     graphics.load_illustration(&root_illustration_name).await;
 
+    // Kept here to not lose it when zone engine is recreated (zone change, reconnection)
+    let user_log_history = Rc::new(RefCell::new(engine::zone::log::UserLogHistory::new()));
+
     let mut current_scene: Box<dyn engine::Engine> =
         Box::new(engine::root::RootScene::new(graphics.clone()));
 
-    // Set egui scale
+    // Set egui scale (egui points are physical pixels based)
     egui_macroquad::ui(|egui_ctx| {
-        egui_ctx.set_pixels_per_point(egui_scale());
+        egui_ctx.set_pixels_per_point(egui_scale() * dpi_scale());
     });
 
     loaded();
 
     loop {
         clear_background(BLACK);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if tileset_watcher.changed(get_time()) {
+            info!("Tileset files changed, reload them");
+            match graphics::load_tileset_files(tileset_image_path, tileset_manifest_path).await {
+                Ok(tileset) => {
+                    graphics.replace_tileset(tileset);
+                }
+                Err(error) => {
+                    error!("Unable to reload tileset : {}", error);
+                }
+            }
+        }
+
         let messages = current_scene.tick();
+        graphics.evict_unused_tile_textures(get_time());
 
         for message in messages {
             match message {
@@ -119,7 +142,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     current_scene = Box::new(engine::description::DescriptionEngine::new(
                         description,
-                        // FIXME : how ot cost ?
                         graphics.clone(),
                         client,
                     ));
@@ -137,7 +159,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 message::MainMessage::AccountCreated => {
                     current_scene = Box::new(engine::root::RootScene::with_home_message(
-                        "Compté créé, identifiez-vous".to_string(),
+                        i18n::tr("account_created").to_string(),
                         Some(egui::Color32::GREEN),
                         graphics.clone(),
                     ));
@@ -164,7 +186,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .await?;
                     graphics.add_avatar_texture(player_avatar_uuid, player_avatar_texture);
 
-                    match engine::zone::ZoneEngine::new(client, graphics.clone(), state) {
+                    match engine::zone::ZoneEngine::new(
+                        client,
+                        graphics.clone(),
+                        state,
+                        user_log_history.clone(),
+                    ) {
                         Ok(engine) => {
                             current_scene = Box::new(engine);
                         }
@@ -180,17 +207,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 message::MainMessage::LoadIllustration(illustration_name) => {
                     info!("Load illustration {}", illustration_name);
                     graphics.load_illustration(&illustration_name).await;
-                    current_scene.replace_graphics(graphics.clone());
-                    current_scene.signal_illustration_loaded(&illustration_name);
                 }
                 message::MainMessage::LoadCharacterSpritesheet(
                     character_id,
                     spritesheet_filename,
                 ) => {
-                    let need_load = if let Some((filename, _)) =
-                        graphics.character_spritesheets.get(&character_id)
+                    let need_load = if let Some(filename) =
+                        graphics.character_spritesheet_filename(&character_id)
                     {
-                        filename != &spritesheet_filename
+                        filename != spritesheet_filename
                     } else {
                         true
                     };
@@ -209,7 +234,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             &spritesheet_filename,
                             character_spritesheet_texture,
                         );
-                        current_scene.replace_graphics(graphics.clone());
                     }
                 }
                 message::MainMessage::SetWorldEngine(client, player) => {
@@ -0,0 +1,50 @@
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{i18n::Language, storage, ui::utils::is_mobile};
+
+const SETTINGS_STORAGE_KEY: &str = "__SETTINGS__";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TilesetResolution {
+    // High resolution tileset if screen have a high pixel density
+    Auto,
+    Standard,
+    High,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Settings {
+    pub camera_min_zoom: f32,
+    pub camera_max_zoom: f32,
+    pub tileset_resolution: TilesetResolution,
+    // Draw blended edges between adjacent terrains
+    pub terrain_transitions: bool,
+    // Display chat messages above speaking characters
+    pub speech_bubbles: bool,
+    pub language: Language,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            camera_min_zoom: 0.5,
+            camera_max_zoom: if is_mobile() { 4.0 } else { 3.0 },
+            tileset_resolution: TilesetResolution::Auto,
+            terrain_transitions: true,
+            speech_bubbles: true,
+            language: Language::French,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        storage::load_json(SETTINGS_STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        storage::save_json(SETTINGS_STORAGE_KEY, self)
+    }
+}
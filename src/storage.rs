@@ -0,0 +1,40 @@
+use macroquad::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Value stored as json under given key (local storage on wasm, file on native). None (and error
+/// logged) if absent or unreadable
+pub fn load_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let storage = &mut quad_storage::STORAGE.lock();
+    let storage = match storage {
+        Ok(storage_) => storage_,
+        Err(error) => {
+            error!("Storage error : '{}'", error);
+            return None;
+        }
+    };
+
+    let value = storage.get(key)?;
+    match serde_json::from_str::<T>(&value) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            error!("Unable to read '{}' from storage : '{}'", key, error);
+            None
+        }
+    }
+}
+
+pub fn save_json<T: Serialize + ?Sized>(key: &str, value: &T) {
+    let storage = &mut quad_storage::STORAGE.lock();
+    let storage = match storage {
+        Ok(storage_) => storage_,
+        Err(error) => {
+            error!("Storage error : '{}'", error);
+            return;
+        }
+    };
+
+    match serde_json::to_string(value) {
+        Ok(value) => storage.set(key, &value),
+        Err(error) => error!("Unable to write '{}' to storage : '{}'", key, error),
+    }
+}
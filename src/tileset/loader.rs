@@ -2,26 +2,112 @@ use ahash::AHashMap;
 
 use crate::config;
 
-use super::{source::TileSource, TileMapping};
+use super::{manifest::TilesetManifest, source::TileSource, TileMapping};
 
-pub fn from_list(
-    source: Vec<(&str, i16, i16, i16)>,
-    tile_width: f32,
-    tile_height: f32,
-) -> TileMapping {
+/// Build tiles mapping from manifest, ensuring each frame is inside the tileset image.
+/// Produced tiles sources are expressed in logical pixels (see `TilesetManifest::atlas_scale`)
+pub fn from_manifest(
+    manifest: &TilesetManifest,
+    image_width: f32,
+    image_height: f32,
+) -> Result<TileMapping, String> {
     let mut mapping = AHashMap::new();
+    let atlas_scale = manifest.atlas_scale();
+    if atlas_scale <= 0. || manifest.tile_height / config::TILE_SIZE != atlas_scale {
+        return Err(format!(
+            "Tile size {}x{} must be a square multiple of {}",
+            manifest.tile_width,
+            manifest.tile_height,
+            config::TILE_SIZE
+        ));
+    }
+
+    for tile in &manifest.tiles {
+        let [x, y, width, height] = tile.rect;
+
+        if tile.frames < 1 {
+            return Err(format!("Tile {} : frames must be at least 1", tile.id));
+        }
+        let frames_end_x = x + width * tile.frames as f32;
+        if x < 0. || y < 0. || frames_end_x > image_width || y + height > image_height {
+            return Err(format!(
+                "Tile {} : frames ({}, {}) -> ({}, {}) are outside of tileset image ({}x{})",
+                tile.id,
+                x,
+                y,
+                frames_end_x,
+                y + height,
+                image_width,
+                image_height
+            ));
+        }
+        if let Some(frame_durations) = &tile.frame_durations {
+            if frame_durations.len() != tile.frames as usize {
+                return Err(format!(
+                    "Tile {} : {} frame durations given for {} frames",
+                    tile.id,
+                    frame_durations.len(),
+                    tile.frames
+                ));
+            }
+            if frame_durations.iter().any(|duration| *duration <= 0.) {
+                return Err(format!(
+                    "Tile {} : frame durations must be positive",
+                    tile.id
+                ));
+            }
+        }
 
-    for (id, row_i, col_i, sprites_count) in source {
         let tile_source = TileSource::new(
-            tile_width * col_i as f32,
-            tile_height * row_i as f32,
-            tile_width,
-            tile_height,
-            sprites_count,
+            x / atlas_scale,
+            y / atlas_scale,
+            width / atlas_scale,
+            height / atlas_scale,
+            tile.frames,
             config::SPRITES_COUNT,
-        );
-        mapping.insert(id.to_string(), tile_source);
+        )
+        .with_frame_durations(tile.frame_durations.clone())
+        .with_transition_priority(tile.transition_priority);
+
+        for tile_id in std::iter::once(&tile.id).chain(tile.aliases.iter()) {
+            if mapping
+                .insert(tile_id.clone(), tile_source.clone())
+                .is_some()
+            {
+                return Err(format!("Tile {} is defined more than once", tile_id));
+            }
+        }
     }
 
-    mapping
+    if !mapping.contains_key("UNKNOWN") {
+        return Err("Tileset manifest must define UNKNOWN tile".to_string());
+    }
+
+    Ok(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shipped_mapping(image_path: &str, manifest_path: &str) -> TileMapping {
+        let (width, height) = image::image_dimensions(image_path).unwrap();
+        let manifest = TilesetManifest::from_bytes(&std::fs::read(manifest_path).unwrap()).unwrap();
+        from_manifest(&manifest, width as f32, height as f32).unwrap()
+    }
+
+    #[test]
+    fn shipped_tilesets_share_logical_tiles() {
+        let standard = shipped_mapping("static/graphics.png", "static/graphics.json");
+        let high = shipped_mapping("static/graphics@2x.png", "static/graphics@2x.json");
+
+        assert_eq!(standard.len(), high.len());
+        for (tile_id, source) in &standard {
+            let high_source = high.get(tile_id).unwrap();
+            assert_eq!(source.width, high_source.width, "{}", tile_id);
+            assert_eq!(source.height, high_source.height, "{}", tile_id);
+            assert_eq!(source.sprites[0].x, high_source.sprites[0].x, "{}", tile_id);
+            assert_eq!(source.sprites[0].y, high_source.sprites[0].y, "{}", tile_id);
+        }
+    }
 }
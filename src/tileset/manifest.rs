@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+use crate::config;
+
+/// Tileset description shipped next to the tileset image (static/graphics.json)
+#[derive(Deserialize, Debug, Clone)]
+pub struct TilesetManifest {
+    // Tile size in image pixels (64 for a high resolution tileset)
+    pub tile_width: f32,
+    pub tile_height: f32,
+    pub tiles: Vec<TileDefinition>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TileDefinition {
+    pub id: String,
+    // x, y, width, height (in image pixels) of the first frame. Next frames follow on the right
+    pub rect: [f32; 4],
+    #[serde(default = "default_frames")]
+    pub frames: i16,
+    // Seconds per frame. When absent, frames follow the zone global animation tick
+    #[serde(default)]
+    pub frame_durations: Option<Vec<f32>>,
+    // Terrain transitions priority. Such terrain is drawn over adjacent lower priority terrains
+    // with its "<id>__EDGE_N" (E, S, W) and "<id>__CORNER_NE" (SE, SW, NW) variants tiles
+    #[serde(default)]
+    pub transition_priority: Option<i16>,
+    // Other tile ids using the same sprites
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+fn default_frames() -> i16 {
+    1
+}
+
+impl TilesetManifest {
+    /// Image pixels per logical pixel
+    pub fn atlas_scale(&self) -> f32 {
+        self.tile_width / config::TILE_SIZE
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(bytes)
+            .map_err(|error| format!("Unable to decode tileset manifest : {}", error))
+    }
+}
@@ -1,7 +1,19 @@
 use ahash::AHashMap;
 
 pub mod loader;
+pub mod manifest;
 pub mod source;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watcher;
 
 pub type TileId = String;
 pub type TileMapping = AHashMap<TileId, source::TileSource>;
+
+pub fn find_tile_id_from_classes(tiles_mapping: &TileMapping, classes: &[String]) -> TileId {
+    for class in classes.iter().rev() {
+        if tiles_mapping.contains_key(class) {
+            return class.clone();
+        }
+    }
+    "UNKNOWN".to_string()
+}
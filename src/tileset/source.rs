@@ -11,6 +11,9 @@ pub struct TileSource {
     pub sprites: Vec<Sprite>,
     pub width: f32,
     pub height: f32,
+    pub frame_durations: Option<Vec<f32>>,
+    // Terrain with a priority spill over adjacent lower priority terrains (see zone::autotile)
+    pub transition_priority: Option<i16>,
 }
 
 impl TileSource {
@@ -24,7 +27,7 @@ impl TileSource {
     ) -> Self {
         let mut sprites = vec![];
 
-        for i in 0..required_sprites_count + 1 {
+        for i in 0..(required_sprites_count + 1).max(real_sprites_count) {
             let sprite_x = if real_sprites_count > i {
                 x + (width * i as f32)
             } else {
@@ -41,9 +44,37 @@ impl TileSource {
             sprites,
             width,
             height,
+            frame_durations: None,
+            transition_priority: None,
         }
     }
 
+    pub fn with_frame_durations(mut self, frame_durations: Option<Vec<f32>>) -> Self {
+        self.frame_durations = frame_durations;
+        self
+    }
+
+    pub fn with_transition_priority(mut self, transition_priority: Option<i16>) -> Self {
+        self.transition_priority = transition_priority;
+        self
+    }
+
+    /// Sprite index to display at given time when tile have its own frame durations
+    pub fn frame_at(&self, time: f64) -> Option<i16> {
+        let frame_durations = self.frame_durations.as_ref()?;
+        let total_duration: f32 = frame_durations.iter().sum();
+        let mut elapsed = (time % total_duration as f64) as f32;
+
+        for (i, duration) in frame_durations.iter().enumerate() {
+            if elapsed < *duration {
+                return Some(i as i16);
+            }
+            elapsed -= duration;
+        }
+
+        Some(frame_durations.len() as i16 - 1)
+    }
+
     pub fn to_rect(&self, sprite_index: i16) -> Rect {
         let sprite = self
             .sprites
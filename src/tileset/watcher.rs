@@ -0,0 +1,47 @@
+use std::{fs, time::SystemTime};
+
+// Seconds between two files modification checks
+const CHECK_INTERVAL: f64 = 1.0;
+
+/// Detect tileset files (image, manifest) modifications to permit hot reload
+pub struct TilesetWatcher {
+    files: Vec<(String, Option<SystemTime>)>,
+    last_check: f64,
+}
+
+impl TilesetWatcher {
+    pub fn new(file_paths: Vec<&str>) -> Self {
+        Self {
+            files: file_paths
+                .into_iter()
+                .map(|file_path| (file_path.to_string(), modified(file_path)))
+                .collect(),
+            last_check: 0.,
+        }
+    }
+
+    /// Return true if one of the files changed since last call
+    pub fn changed(&mut self, now: f64) -> bool {
+        if now - self.last_check < CHECK_INTERVAL {
+            return false;
+        }
+        self.last_check = now;
+
+        let mut changed = false;
+        for (file_path, last_modified) in self.files.iter_mut() {
+            let modified = modified(file_path);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed = true;
+            }
+        }
+
+        changed
+    }
+}
+
+fn modified(file_path: &str) -> Option<SystemTime> {
+    fs::metadata(file_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
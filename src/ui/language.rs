@@ -0,0 +1,23 @@
+use crate::i18n::{self, Language};
+
+/// Language combo box, applying chosen language immediately. Return true if it changed
+pub fn language_selector(ui: &mut egui::Ui, language: &mut Language) -> bool {
+    let mut changed = false;
+
+    ui.label(i18n::tr("settings.language"));
+    egui::ComboBox::from_id_source("language_selector")
+        .selected_text(language.name())
+        .show_ui(ui, |ui| {
+            for language_ in Language::ALL {
+                if ui
+                    .selectable_value(language, language_, language_.name())
+                    .changed()
+                {
+                    i18n::set_language(language_);
+                    changed = true;
+                }
+            }
+        });
+
+    changed
+}
@@ -1,2 +1,3 @@
+pub mod language;
 pub mod text_input;
 pub mod utils;
@@ -1,3 +1,5 @@
+use macroquad::window::get_internal_gl;
+
 #[cfg(target_arch = "wasm32")]
 use sapp_jsutils::JsObject;
 
@@ -47,6 +49,11 @@ pub fn egui_scale() -> f32 {
     }
 }
 
+/// Physical pixels per logical pixel of the window
+pub fn dpi_scale() -> f32 {
+    unsafe { get_internal_gl().quad_context.dpi_scale() }
+}
+
 pub fn open_url(url: &str) -> bool {
     #[cfg(not(target_arch = "wasm32"))]
     {
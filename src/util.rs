@@ -191,3 +191,69 @@ pub fn get_text_center(
 
     crate::Vec2::new(x_center, y_center)
 }
+
+/// Current timestamp (seconds since epoch), available on native and wasm
+pub fn now() -> f64 {
+    macroquad::miniquad::date::now()
+}
+
+#[cfg(target_arch = "wasm32")]
+extern "C" {
+    // Minutes to add to local time to obtain UTC (as javascript Date.getTimezoneOffset)
+    fn _timezone_offset() -> i32;
+}
+
+/// Seconds to add to UTC time to obtain local time at given timestamp
+#[cfg(target_arch = "wasm32")]
+fn local_time_offset(_timestamp: f64) -> i64 {
+    -(unsafe { _timezone_offset() } as i64) * 60
+}
+
+/// Seconds to add to UTC time to obtain local time at given timestamp
+#[cfg(not(target_arch = "wasm32"))]
+fn local_time_offset(timestamp: f64) -> i64 {
+    let time = timestamp as libc::time_t;
+    let mut local: libc::tm = unsafe { std::mem::zeroed() };
+    #[cfg(unix)]
+    let ok = !unsafe { libc::localtime_r(&time, &mut local) }.is_null();
+    #[cfg(windows)]
+    let ok = unsafe { libc::localtime_s(&mut local, &time) } == 0;
+    if !ok {
+        return 0;
+    }
+
+    // Local date and time, read as if it was UTC, minus real timestamp
+    let (year, month, day) = (
+        local.tm_year as i64 + 1900,
+        local.tm_mon as i64 + 1,
+        local.tm_mday as i64,
+    );
+    let local_seconds = days_from_civil(year, month, day) * 86400
+        + local.tm_hour as i64 * 3600
+        + local.tm_min as i64 * 60
+        + local.tm_sec as i64;
+    local_seconds - time as i64
+}
+
+/// Days since 1970-01-01 of given date (proleptic gregorian calendar)
+#[cfg(not(target_arch = "wasm32"))]
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Format given timestamp as "HH:MM:SS" (local time)
+pub fn format_time(timestamp: f64) -> String {
+    let seconds_of_day = (timestamp as i64 + local_time_offset(timestamp)).rem_euclid(86400);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60
+    )
+}
@@ -0,0 +1,158 @@
+use crate::tileset::{TileId, TileMapping};
+
+use super::map::ZoneMap;
+
+// Neighbours offsets (row, col) and matching variant suffix. A variant of terrain X named
+// "X__EDGE_N" is drawn over a tile which have X as north neighbour (X bleeding from the top)
+const EDGES: [((i32, i32), &str); 4] = [
+    ((-1, 0), "EDGE_N"),
+    ((0, 1), "EDGE_E"),
+    ((1, 0), "EDGE_S"),
+    ((0, -1), "EDGE_W"),
+];
+// Corners are only used when none of the two adjacent edges are the same terrain
+const CORNERS: [((i32, i32), &str, usize, usize); 4] = [
+    ((-1, 1), "CORNER_NE", 0, 1),
+    ((1, 1), "CORNER_SE", 2, 1),
+    ((1, -1), "CORNER_SW", 2, 3),
+    ((-1, -1), "CORNER_NW", 0, 3),
+];
+
+/// Terrain transitions overlays (edge and corner variants of the tileset) of each zone tile
+pub struct Autotiles {
+    width: usize,
+    height: usize,
+    overlays: Vec<Vec<TileId>>,
+}
+
+impl Autotiles {
+    /// Autotiles without any overlay (used when terrain transitions are disabled)
+    pub fn empty(map: &ZoneMap) -> Self {
+        Self {
+            width: map.width,
+            height: map.height,
+            overlays: vec![vec![]; map.width * map.height],
+        }
+    }
+
+    pub fn new(map: &ZoneMap, tiles_mapping: &TileMapping) -> Self {
+        let mut autotiles = Self::empty(map);
+
+        for row_i in 0..map.height as i32 {
+            for col_i in 0..map.width as i32 {
+                autotiles.compute(map, tiles_mapping, row_i, col_i);
+            }
+        }
+
+        autotiles
+    }
+
+    /// Overlays to draw over the tile, in drawing order
+    pub fn overlays(&self, row_i: i32, col_i: i32) -> &[TileId] {
+        match self.offset(row_i, col_i) {
+            Some(offset) => &self.overlays[offset],
+            None => &[],
+        }
+    }
+
+    /// Recompute the tile and its neighbours overlays (after tile replacement)
+    pub fn update_around(
+        &mut self,
+        map: &ZoneMap,
+        tiles_mapping: &TileMapping,
+        row_i: i32,
+        col_i: i32,
+    ) {
+        for row_offset in -1..=1 {
+            for col_offset in -1..=1 {
+                self.compute(map, tiles_mapping, row_i + row_offset, col_i + col_offset);
+            }
+        }
+    }
+
+    fn offset(&self, row_i: i32, col_i: i32) -> Option<usize> {
+        if row_i < 0 || col_i < 0 || row_i as usize >= self.height || col_i as usize >= self.width {
+            return None;
+        }
+
+        Some(row_i as usize * self.width + col_i as usize)
+    }
+
+    fn compute(&mut self, map: &ZoneMap, tiles_mapping: &TileMapping, row_i: i32, col_i: i32) {
+        let offset = match self.offset(row_i, col_i) {
+            Some(offset) => offset,
+            None => return,
+        };
+        let priority = |tile_id: &str| {
+            tiles_mapping
+                .get(tile_id)
+                .and_then(|tile_source| tile_source.transition_priority)
+        };
+
+        let mut overlays = vec![];
+        if let Some(tile_id) = terrain_at(map, row_i, col_i) {
+            let tile_priority = priority(tile_id);
+
+            // Neighbour terrains which spill over this tile, lowest priority first
+            let mut terrains: Vec<(i16, &str)> = vec![];
+            for row_offset in -1..=1 {
+                for col_offset in -1..=1 {
+                    if let Some(neighbour_id) =
+                        terrain_at(map, row_i + row_offset, col_i + col_offset)
+                    {
+                        if let Some(neighbour_priority) = priority(neighbour_id) {
+                            if Some(neighbour_priority) > tile_priority
+                                && !terrains.contains(&(neighbour_priority, neighbour_id))
+                            {
+                                terrains.push((neighbour_priority, neighbour_id));
+                            }
+                        }
+                    }
+                }
+            }
+            terrains.sort();
+
+            for (_, terrain) in terrains {
+                let is_terrain = |(row_offset, col_offset): (i32, i32)| {
+                    terrain_at(map, row_i + row_offset, col_i + col_offset) == Some(terrain)
+                };
+                let edges: Vec<bool> = EDGES
+                    .iter()
+                    .map(|(neighbour, _)| is_terrain(*neighbour))
+                    .collect();
+
+                let mut variants = vec![];
+                for ((_, suffix), edge) in EDGES.iter().zip(&edges) {
+                    if *edge {
+                        variants.push(suffix);
+                    }
+                }
+                for (neighbour, suffix, edge1, edge2) in &CORNERS {
+                    if !edges[*edge1] && !edges[*edge2] && is_terrain(*neighbour) {
+                        variants.push(suffix);
+                    }
+                }
+
+                for suffix in variants {
+                    let variant_id = format!("{}__{}", terrain, suffix);
+                    if tiles_mapping.contains_key(&variant_id) {
+                        overlays.push(variant_id);
+                    }
+                }
+            }
+        }
+
+        self.overlays[offset] = overlays;
+    }
+}
+
+fn terrain_at(map: &ZoneMap, row_i: i32, col_i: i32) -> Option<&str> {
+    if row_i < 0 || col_i < 0 {
+        return None;
+    }
+
+    map.tiles
+        .get(row_i as usize)
+        .and_then(|row| row.get(col_i as usize))
+        .map(|tile_id| tile_id.as_str())
+}
@@ -1,6 +1,8 @@
 use crate::tileset;
 
+pub mod autotile;
 pub mod load;
 pub mod map;
+pub mod render;
 
 pub type ZoneMapTiles = Vec<Vec<tileset::TileId>>;
@@ -0,0 +1,214 @@
+use image::{imageops, DynamicImage, RgbaImage};
+
+use crate::{
+    entity::{build::Build, resource::Resource, stuff::Stuff},
+    tileset::{self, TileMapping},
+};
+
+use super::{autotile::Autotiles, map::ZoneMap};
+
+const UNDER_CONSTRUCTION_TILE_ID: &str = "LITTLE_SHOVEL";
+
+/// Compose zone images without any GPU (only tile set image and tiles mapping are used).
+/// Tile sizes are logical ones, produced image use the tile set image resolution
+pub struct ZoneRenderer<'a> {
+    tile_set_image: &'a DynamicImage,
+    tiles_mapping: &'a TileMapping,
+    // Output tile size, in tile set image pixels
+    tile_width: u32,
+    tile_height: u32,
+    atlas_scale: f32,
+}
+
+impl<'a> ZoneRenderer<'a> {
+    pub fn new(
+        tile_set_image: &'a DynamicImage,
+        tiles_mapping: &'a TileMapping,
+        tile_width: f32,
+        tile_height: f32,
+        atlas_scale: f32,
+    ) -> Self {
+        Self {
+            tile_set_image,
+            tiles_mapping,
+            tile_width: (tile_width * atlas_scale) as u32,
+            tile_height: (tile_height * atlas_scale) as u32,
+            atlas_scale,
+        }
+    }
+
+    pub fn render(
+        &self,
+        map: &ZoneMap,
+        builds: &Vec<Build>,
+        stuffs: &Vec<Stuff>,
+        resources: &Vec<Resource>,
+    ) -> RgbaImage {
+        let mut image = RgbaImage::new(
+            map.width as u32 * self.tile_width,
+            map.height as u32 * self.tile_height,
+        );
+
+        // Zone tiles, with their terrain transitions
+        let autotiles = Autotiles::new(map, self.tiles_mapping);
+        for (row_i, row) in map.tiles.iter().enumerate() {
+            for (col_i, tile_id) in row.iter().enumerate() {
+                if tile_id == "UNKNOWN" || tile_id == "NOTHING" {
+                    continue;
+                }
+
+                self.draw_tile(
+                    &mut image,
+                    row_i as i32,
+                    col_i as i32,
+                    &map.background_tile_id,
+                );
+                self.draw_tile(&mut image, row_i as i32, col_i as i32, tile_id);
+                for overlay_tile_id in autotiles.overlays(row_i as i32, col_i as i32) {
+                    self.draw_tile(&mut image, row_i as i32, col_i as i32, overlay_tile_id);
+                }
+            }
+        }
+
+        // Builds
+        for build in builds {
+            let tile_id =
+                tileset::find_tile_id_from_classes(self.tiles_mapping, &build.get_classes());
+            self.draw_tile(&mut image, build.row_i, build.col_i, &tile_id);
+
+            if build.under_construction {
+                self.draw_tile(
+                    &mut image,
+                    build.row_i,
+                    build.col_i,
+                    UNDER_CONSTRUCTION_TILE_ID,
+                );
+            }
+        }
+
+        // Resources
+        for resource in resources {
+            let classes = vec!["RESOURCE_GENERIC".to_string(), resource.id.clone()];
+            let tile_id = tileset::find_tile_id_from_classes(self.tiles_mapping, &classes);
+            self.draw_tile(
+                &mut image,
+                resource.zone_row_i,
+                resource.zone_col_i,
+                &tile_id,
+            );
+        }
+
+        // Stuffs
+        for stuff in stuffs {
+            let tile_id =
+                tileset::find_tile_id_from_classes(self.tiles_mapping, &stuff.get_classes());
+            self.draw_tile(&mut image, stuff.zone_row_i, stuff.zone_col_i, &tile_id);
+        }
+
+        image
+    }
+
+    fn draw_tile(&self, image: &mut RgbaImage, row_i: i32, col_i: i32, tile_id: &str) {
+        let tile_source = match self.tiles_mapping.get(tile_id) {
+            Some(tile_source) => tile_source,
+            None => match self.tiles_mapping.get("UNKNOWN") {
+                Some(tile_source) => tile_source,
+                None => return,
+            },
+        };
+        let sprite = &tile_source.sprites[0];
+        let tile_image = self
+            .tile_set_image
+            .crop_imm(
+                (sprite.x * self.atlas_scale) as u32,
+                (sprite.y * self.atlas_scale) as u32,
+                (tile_source.width * self.atlas_scale) as u32,
+                (tile_source.height * self.atlas_scale) as u32,
+            )
+            .to_rgba8();
+
+        imageops::overlay(
+            image,
+            &tile_image,
+            (col_i * self.tile_width as i32) as i64,
+            (row_i * self.tile_height as i32) as i64,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgba;
+
+    use super::*;
+    use crate::{config::TILE_SIZE, tileset::loader, tileset::manifest::TilesetManifest};
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+
+    // Tile set image with a red PLAIN tile followed by a blue SEA tile
+    fn tile_set(tile_size: u32) -> (DynamicImage, TilesetManifest) {
+        let image = RgbaImage::from_fn(tile_size * 2, tile_size, |x, _| {
+            if x < tile_size {
+                RED
+            } else {
+                BLUE
+            }
+        });
+        let manifest = TilesetManifest::from_bytes(
+            format!(
+                r#"{{"tile_width": {size}, "tile_height": {size}, "tiles": [
+                    {{"id": "UNKNOWN", "rect": [0, 0, {size}, {size}]}},
+                    {{"id": "PLAIN", "rect": [0, 0, {size}, {size}]}},
+                    {{"id": "SEA", "rect": [{size}, 0, {size}, {size}]}}
+                ]}}"#,
+                size = tile_size
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        (DynamicImage::ImageRgba8(image), manifest)
+    }
+
+    fn render(tile_size: u32) -> RgbaImage {
+        let (image, manifest) = tile_set(tile_size);
+        let tiles_mapping =
+            loader::from_manifest(&manifest, image.width() as f32, image.height() as f32).unwrap();
+        let map = ZoneMap::new(
+            vec![],
+            vec![vec!["SEA".to_string(), "PLAIN".to_string()]],
+            "PLAIN",
+            TILE_SIZE,
+            TILE_SIZE,
+        );
+
+        ZoneRenderer::new(
+            &image,
+            &tiles_mapping,
+            TILE_SIZE,
+            TILE_SIZE,
+            manifest.atlas_scale(),
+        )
+        .render(&map, &vec![], &vec![], &vec![])
+    }
+
+    #[test]
+    fn tiles_are_drawn_at_their_position() {
+        let image = render(32);
+
+        assert_eq!(image.dimensions(), (64, 32));
+        assert_eq!(image.get_pixel(0, 0), &BLUE);
+        assert_eq!(image.get_pixel(31, 31), &BLUE);
+        assert_eq!(image.get_pixel(32, 0), &RED);
+        assert_eq!(image.get_pixel(63, 31), &RED);
+    }
+
+    #[test]
+    fn high_resolution_tile_set_produce_bigger_image() {
+        let image = render(64);
+
+        assert_eq!(image.dimensions(), (128, 64));
+        assert_eq!(image.get_pixel(63, 63), &BLUE);
+        assert_eq!(image.get_pixel(64, 0), &RED);
+    }
+}